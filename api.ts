@@ -19,7 +19,8 @@ export interface Asset {
 }
 
 // There is no need to work with values as with numbers on the frontend.
-// Some values may exceed the allowable number sizes in js
+// Some values may exceed the allowable number sizes in js.
+// `Fixed` is an exact decimal without exponent and trailing zeros, e.g. `-1.5`
 export type AssetValue =
   | Tagged<"Quantity", string>
   | Tagged<"BigQuantity", string>
@@ -39,6 +40,10 @@ export interface AssetDefinitionWithAccounts extends AssetDefinition {
    * List of account IDs
    */
   accounts: string[];
+  /**
+   * Amount of distinct accounts holding the asset. Present only with `include_totals=true`
+   */
+  holders?: number;
 }
 
 export type Mintable = "Once" | "Infinitely" | "Not";
@@ -53,6 +58,17 @@ export interface Role {
   permissions: PermissionToken[];
 }
 
+/**
+ * Chain parameter, e.g. `MaxTransactionsInBlock`
+ */
+export interface Parameter {
+  id: string;
+  /**
+   * Value of the parameter, as Iroha formats it, e.g. `"512"`
+   */
+  value: string;
+}
+
 export interface PermissionToken {
   name: string;
   params: any;
@@ -66,6 +82,10 @@ export interface Account {
   roles: Role[];
   signature_check_condition: any;
   metadata: any;
+  /**
+   * `true` if `metadata` is too large and is omitted in list responses
+   */
+  metadata_truncated: boolean;
 }
 
 export interface Domain {
@@ -74,6 +94,10 @@ export interface Domain {
   asset_definitions: AssetDefinition[];
   logo: null | string;
   metadata: any;
+  /**
+   * `true` if `metadata` is too large and is omitted in list responses
+   */
+  metadata_truncated: boolean;
   /**
    * amount of triggers, always 0 for now
    */
@@ -103,6 +127,20 @@ export interface Status {
   };
 }
 
+export interface Info {
+  explorer_version: string;
+  /**
+   * Iroha version the explorer is built against, e.g. `RC_16`
+   */
+  iroha_compat: string;
+  torii_url: string;
+}
+
+export interface BlockHash {
+  height: number;
+  block_hash: string;
+}
+
 export interface BlockShallow {
   height: number;
   /**
@@ -142,6 +180,18 @@ export interface Block {
    * List of hashes. WIP always empty
    */
   view_change_proofs: string[];
+  /**
+   * Total amount of instructions in the block's transactions.
+   * `null` if some of them are WASM smart contracts, whose instructions can't be counted
+   */
+  instructions_total: null | number;
+  /**
+   * Amounts of transactions by the kind of their executable
+   */
+  executables: {
+    instructions: number;
+    wasm: number;
+  };
 }
 
 export interface TransactionDTO {
@@ -156,9 +206,25 @@ export interface TransactionDTO {
   rejection_reason?: string;
 }
 
+export interface Instruction {
+  transaction_hash: string;
+  /**
+   * Zero-based index of the instruction in the transaction
+   */
+  index: number;
+  /**
+   * Serialized {@link @iroha2/data-model#InstructionBox}
+   */
+  instruction: string;
+}
+
 export interface TransactionPayload {
   account_id: string;
   instructions: TransactionInstructions;
+  /**
+   * Present only if `instructions` is `Wasm`
+   */
+  wasm: null | WasmInfo;
   /**
    * ISO timestamp
    */
@@ -176,6 +242,17 @@ export type TransactionInstructions =
   | Tagged<"Instructions", string[]>
   | Tagged<"Wasm", undefined>;
 
+export interface WasmInfo {
+  /**
+   * Size of the module in bytes
+   */
+  size: number;
+  /**
+   * Hex SHA-256 hash of the module
+   */
+  sha256: string;
+}
+
 export interface Signature {
   /**
    * Public key's multihash
@@ -1,5 +1,5 @@
 use super::logger;
-use crate::iroha_client_wrap::{IrohaClientWrap, QueryBuilder};
+use crate::iroha_client_wrap::{HttpClientConfig, IrohaClientWrap, QueryBuilder};
 use actix::{
     prelude::{Actor, Addr, AsyncContext, Context, Handler, Message},
     ActorFutureExt, ResponseActFuture, WrapFuture,
@@ -29,9 +29,13 @@ pub struct DevActor {
 }
 
 impl DevActor {
-    pub fn start(client: Arc<iroha_client::client::Client>, account_id: AccountId) -> Addr<Self> {
+    pub fn start(
+        client: Arc<iroha_client::client::Client>,
+        http_config: &HttpClientConfig,
+        account_id: AccountId,
+    ) -> Addr<Self> {
         let work = RandomWorkState {
-            client: IrohaClientWrap::new(client),
+            client: IrohaClientWrap::new(client, http_config),
             account_id,
             rng: rand::thread_rng(),
         };
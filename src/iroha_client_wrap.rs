@@ -1,4 +1,4 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 
 use awc::{Client as ActixClient, ClientResponse as RespActix};
 use color_eyre::{
@@ -181,6 +181,42 @@ mod request_builder {
     }
 }
 
+/// `User-Agent` header value of the explorer of the given version, e.g. `iroha2_explorer_web/0.1.0`
+pub fn user_agent(version: &str) -> String {
+    format!("{}/{version}", env!("CARGO_PKG_NAME"))
+}
+
+/// Settings of the HTTP client used to communicate with Iroha
+#[derive(Debug, Clone)]
+pub struct HttpClientConfig {
+    /// Timeout for establishing a connection
+    pub connect_timeout: Duration,
+    /// Timeout for a whole request, including receiving of the response
+    pub request_timeout: Duration,
+    /// `User-Agent` header value sent with every HTTP request to Iroha. See [`user_agent`].
+    pub user_agent: String,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            request_timeout: Duration::from_secs(5),
+            user_agent: user_agent(env!("CARGO_PKG_VERSION")),
+        }
+    }
+}
+
+impl HttpClientConfig {
+    fn build_client(&self) -> ActixClient {
+        ActixClient::builder()
+            .connector(awc::Connector::new().timeout(self.connect_timeout))
+            .timeout(self.request_timeout)
+            .add_default_header((awc::http::header::USER_AGENT, self.user_agent.clone()))
+            .finish()
+    }
+}
+
 pub struct IrohaClientWrap {
     iroha: Arc<IrohaClient>,
     http: ActixClient,
@@ -224,10 +260,10 @@ where
 }
 
 impl IrohaClientWrap {
-    pub fn new(iroha_client: Arc<IrohaClient>) -> Self {
+    pub fn new(iroha_client: Arc<IrohaClient>, http_config: &HttpClientConfig) -> Self {
         Self {
             iroha: iroha_client,
-            http: ActixClient::default(),
+            http: http_config.build_client(),
         }
     }
 
@@ -278,3 +314,205 @@ impl IrohaClientWrap {
         Ok(())
     }
 }
+
+/// Helpers for tests that need an Iroha client, but don't need a real Iroha
+#[cfg(test)]
+pub mod test_utils {
+    use super::{Arc, IrohaClient, Pagination, PredicateBox, Sorting, Value};
+    use actix_web::{web, App, HttpResponse, HttpServer};
+    use iroha_config::client::Configuration;
+    use iroha_data_model::{
+        prelude::{Account, AccountId, AssetDefinition, Domain, IdentifiableBox},
+        query::{PaginatedQueryResult, QueryResult, VersionedPaginatedQueryResult},
+        Registrable, ValidationFail,
+    };
+    use parity_scale_codec::Encode;
+    use std::sync::Mutex;
+
+    /// Client of `alice@wonderland` that sends requests to `torii_url`
+    pub fn client(torii_url: &str) -> Arc<IrohaClient> {
+        let config: Configuration = serde_json::from_value(serde_json::json!({
+            "PUBLIC_KEY": "ed01207233BFC89DCBD68C19FDE6CE6158225298EC1131B6A130D1AEB454C1AB5183C0",
+            "PRIVATE_KEY": {
+                "digest_function": "ed25519",
+                "payload": "9ac47abf59b356e0bd7dcbbbb4dec080e302156a48ca907e47cb6aea1d32719e7233bfc89dcbd68c19fde6ce6158225298ec1131b6a130d1aeb454c1ab5183c0"
+            },
+            "ACCOUNT_ID": "alice@wonderland",
+            "BASIC_AUTH": { "web_login": "mad_hatter", "password": "ilovetea" },
+            "TORII_API_URL": torii_url,
+            "TORII_TELEMETRY_URL": torii_url,
+            "TRANSACTION_TIME_TO_LIVE_MS": 100_000,
+            "TRANSACTION_STATUS_TIMEOUT_MS": 15_000,
+            "TRANSACTION_LIMITS": { "max_instruction_number": 4096, "max_wasm_size_bytes": 4_194_304 },
+            "ADD_TRANSACTION_NONCE": false
+        }))
+        .unwrap();
+        Arc::new(IrohaClient::new(&config).unwrap())
+    }
+
+    /// Local port that nothing listens on
+    pub fn free_port() -> u16 {
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        listener.local_addr().unwrap().port()
+    }
+
+    /// URL of a port that nothing listens on
+    pub fn unreachable_url() -> String {
+        format!("http://127.0.0.1:{}/", free_port())
+    }
+
+    /// Reply of a stub Torii to a query: either its output, or a validation failure
+    pub type StubReply = Result<Value, ValidationFail>;
+
+    type StubReplies = Mutex<Box<dyn Iterator<Item = StubReply> + Send>>;
+
+    /// Failure Iroha replies with when the queried entity doesn't exist
+    pub fn not_found() -> ValidationFail {
+        ValidationFail::NotPermitted("Entity does not exist".to_owned())
+    }
+
+    /// URL of a Torii that fails validation of every query, as Iroha does when the queried
+    /// entity doesn't exist. Must be called within an actix runtime.
+    pub fn rejecting_torii_url() -> String {
+        stub_torii_url(std::iter::repeat_with(|| Err(not_found())))
+    }
+
+    /// URL of a Torii that replies to queries with `replies`, in the order the queries
+    /// are made, and fails any query beyond them. Must be called within an actix runtime.
+    pub fn scripted_torii_url(replies: Vec<StubReply>) -> String {
+        stub_torii_url(replies.into_iter())
+    }
+
+    fn stub_torii_url(replies: impl Iterator<Item = StubReply> + Send + 'static) -> String {
+        let replies: web::Data<StubReplies> = web::Data::new(Mutex::new(Box::new(replies)));
+        let server = HttpServer::new(move || {
+            App::new()
+                .app_data(replies.clone())
+                .default_service(web::route().to(reply))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let addr = server.addrs()[0];
+        actix_web::rt::spawn(server.run());
+        format!("http://{addr}/")
+    }
+
+    async fn reply(replies: web::Data<StubReplies>) -> HttpResponse {
+        let reply = replies.lock().unwrap().next();
+        match reply {
+            Some(Ok(value)) => {
+                let total = match &value {
+                    Value::Vec(items) => items.len().try_into().unwrap(),
+                    _ => 1,
+                };
+                let result = VersionedPaginatedQueryResult::V1(PaginatedQueryResult {
+                    result: QueryResult(value),
+                    filter: PredicateBox::default(),
+                    pagination: Pagination::default(),
+                    sorting: Sorting::default(),
+                    total,
+                });
+                HttpResponse::Ok().body(result.encode())
+            }
+            Some(Err(fail)) => HttpResponse::NotFound().body(fail.encode()),
+            None => HttpResponse::InternalServerError().body("No reply is scripted for the query"),
+        }
+    }
+
+    fn alice() -> AccountId {
+        "alice@wonderland".parse().unwrap()
+    }
+
+    /// Domain without accounts and asset definitions, as a query output
+    pub fn domain(id: &str) -> Value {
+        let domain = Domain::new(id.parse().unwrap()).build(&alice());
+        Value::Identifiable(IdentifiableBox::from(domain))
+    }
+
+    /// Account without assets, as a query output
+    pub fn account(id: &str) -> Value {
+        let account = Account::new(id.parse().unwrap(), []).build(&alice());
+        Value::Identifiable(IdentifiableBox::from(account))
+    }
+
+    /// Quantity asset definition, as a query output
+    pub fn asset_definition(id: &str) -> Value {
+        let definition = AssetDefinition::quantity(id.parse().unwrap()).build(&alice());
+        Value::Identifiable(IdentifiableBox::from(definition))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+    use awc::error::SendRequestError;
+
+    /// Starts a server on a random port and returns its URL
+    fn start_server() -> String {
+        let server = HttpServer::new(|| {
+            App::new()
+                .route(
+                    "/user-agent",
+                    web::get().to(|req: HttpRequest| async move {
+                        let user_agent = req
+                            .headers()
+                            .get(awc::http::header::USER_AGENT)
+                            .and_then(|value| value.to_str().ok())
+                            .unwrap_or_default()
+                            .to_owned();
+                        HttpResponse::Ok().body(user_agent)
+                    }),
+                )
+                .route(
+                    "/slow",
+                    web::get().to(|| async {
+                        actix_web::rt::time::sleep(Duration::from_millis(500)).await;
+                        HttpResponse::Ok().finish()
+                    }),
+                )
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let addr = server.addrs()[0];
+        actix_web::rt::spawn(server.run());
+        format!("http://{addr}")
+    }
+
+    #[actix_web::test]
+    async fn http_client_sends_user_agent() {
+        let url = start_server();
+        let client = HttpClientConfig {
+            user_agent: user_agent("1.2.3-custom"),
+            ..HttpClientConfig::default()
+        }
+        .build_client();
+
+        let body = client
+            .get(format!("{url}/user-agent"))
+            .send()
+            .await
+            .unwrap()
+            .body()
+            .await
+            .unwrap();
+
+        assert_eq!(body, "iroha2_explorer_web/1.2.3-custom");
+    }
+
+    #[actix_web::test]
+    async fn http_client_respects_request_timeout() {
+        let url = start_server();
+        let client = HttpClientConfig {
+            request_timeout: Duration::from_millis(100),
+            ..HttpClientConfig::default()
+        }
+        .build_client();
+
+        let err = client.get(format!("{url}/slow")).send().await.unwrap_err();
+
+        assert!(matches!(err, SendRequestError::Timeout));
+    }
+}
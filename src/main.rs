@@ -1,7 +1,7 @@
 #![allow(clippy::module_name_repetitions)]
 
 mod logger {
-    use tracing::{subscriber::set_global_default, Subscriber};
+    use tracing::{level_filters::LevelFilter, subscriber::set_global_default, Subscriber};
     pub use tracing_actix_web::TracingLogger;
     use tracing_log::LogTracer;
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter, Registry};
@@ -9,29 +9,108 @@ mod logger {
     pub use tracing::{debug, error, info, warn};
 
     /// Compose multiple layers into a `tracing`'s subscriber.
-    fn get_subscriber(default_env_filter: String) -> impl Subscriber + Send + Sync {
-        let env_filter_layer = EnvFilter::try_from_default_env()
-            .unwrap_or_else(|_| EnvFilter::new(default_env_filter));
+    ///
+    /// If `level` is provided, it overrides the global level of the env filter, while
+    /// per-target directives from the env are kept.
+    fn get_subscriber(
+        default_env_filter: String,
+        level: Option<LevelFilter>,
+    ) -> impl Subscriber + Send + Sync {
+        let env_filter_layer = with_level(
+            EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| EnvFilter::new(default_env_filter)),
+            level,
+        );
 
         let fmt_layer = tracing_subscriber::fmt::layer().compact();
 
         Registry::default().with(env_filter_layer).with(fmt_layer)
     }
 
-    pub fn setup() {
+    /// Replaces the global level of `filter` with `level`, if it is provided
+    fn with_level(filter: EnvFilter, level: Option<LevelFilter>) -> EnvFilter {
+        match level {
+            Some(level) => filter.add_directive(level.into()),
+            None => filter,
+        }
+    }
+
+    pub fn setup(level: Option<LevelFilter>) {
         LogTracer::init().expect("Failed to set logger");
 
-        let subscriber = get_subscriber("info".into());
+        let subscriber = get_subscriber("info".into(), level);
         set_global_default(subscriber).expect("Failed to set subscriber");
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::collections::BTreeSet;
+
+        fn directives(filter: &EnvFilter) -> BTreeSet<String> {
+            filter.to_string().split(',').map(str::to_owned).collect()
+        }
+
+        #[test]
+        fn level_overrides_global_directive() {
+            let filter = with_level(EnvFilter::new("info"), Some(LevelFilter::DEBUG));
+
+            assert_eq!(directives(&filter), BTreeSet::from(["debug".to_owned()]));
+        }
+
+        #[test]
+        fn per_target_directives_survive_level() {
+            let filter = with_level(
+                EnvFilter::new("info,actix_web=trace,hyper=warn"),
+                Some(LevelFilter::ERROR),
+            );
+
+            assert_eq!(
+                directives(&filter),
+                BTreeSet::from([
+                    "actix_web=trace".to_owned(),
+                    "error".to_owned(),
+                    "hyper=warn".to_owned(),
+                ])
+            );
+        }
+
+        #[test]
+        fn no_level_keeps_env_directives() {
+            let filter = with_level(EnvFilter::new("warn,hyper=debug"), None);
+
+            assert_eq!(
+                directives(&filter),
+                BTreeSet::from(["hyper=debug".to_owned(), "warn".to_owned()])
+            );
+        }
+    }
 }
 
 /// App CLI arguments specific logic
 mod args {
-    use clap::Parser;
+    use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, ValueSource};
     use color_eyre::{eyre::Context as _, Help as _, Result};
     use iroha_client::client::Client as IrohaClient;
     use iroha_config::client::Configuration as IrohaClientConfiguration;
+    use serde::Deserialize;
+    use std::time::Duration;
+    use tracing::level_filters::LevelFilter;
+
+    use crate::iroha_client_wrap::{user_agent, HttpClientConfig};
+
+    /// Env variable that overrides the version reported by `--version`, e.g. by packagers
+    const VERSION_ENV: &str = "EXPLORER_VERSION";
+
+    /// Version of the explorer, as reported by `--version`
+    pub fn version() -> String {
+        version_or_default(std::env::var(VERSION_ENV).ok())
+    }
+
+    /// Returns `version_override` if it is set, the crate version otherwise
+    fn version_or_default(version_override: Option<String>) -> String {
+        version_override.unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_owned())
+    }
 
     #[derive(Debug, Parser)]
     #[clap(about = "Iroha 2 Explorer Backend", version, long_about = None)]
@@ -43,6 +122,31 @@ mod args {
         #[clap(short = 'c', long, default_value = "client_config.json")]
         pub client_config: String,
 
+        /// JSON file with explorer settings. Flags and env variables take precedence over it
+        #[clap(long)]
+        pub config: Option<String>,
+
+        /// Timeout for establishing a connection to Iroha, in milliseconds
+        #[clap(long, default_value = "5000")]
+        pub connect_timeout_ms: u64,
+
+        /// Timeout for a whole request to Iroha, in milliseconds
+        #[clap(long, default_value = "5000")]
+        pub request_timeout_ms: u64,
+
+        /// Max JSON size of an entity metadata in list responses, in bytes.
+        /// Larger metadata is omitted there, but is available in single entity responses
+        #[clap(long, default_value = "4096")]
+        pub list_metadata_max_bytes: usize,
+
+        /// Decrease logging verbosity (`-q` for warnings, `-qq` for errors only)
+        #[clap(short, long, action = clap::ArgAction::Count, conflicts_with = "verbose")]
+        pub quiet: u8,
+
+        /// Increase logging verbosity (`-v` for debug, `-vv` for trace)
+        #[clap(short, long, action = clap::ArgAction::Count)]
+        pub verbose: u8,
+
         /// Run actor that fills Iroha with fake data
         #[cfg(feature = "dev_actor")]
         #[clap(long)]
@@ -50,8 +154,107 @@ mod args {
     }
 
     impl Args {
-        pub fn parse() -> Self {
-            Parser::parse()
+        pub fn parse() -> Result<Self> {
+            let version = version();
+            let matches = Self::command().version(version.as_str()).get_matches();
+            Self::from_matches(&matches)
+        }
+
+        /// Constructs args from `matches`, filling the values that are left default
+        /// from the `--config` file, if it is specified
+        fn from_matches(matches: &ArgMatches) -> Result<Self> {
+            let mut args = Self::from_arg_matches(matches).unwrap_or_else(|err| err.exit());
+            if let Some(path) = &args.config {
+                let file = ConfigFile::load(path)?;
+                args.merge_config_file(file, matches);
+            }
+            Ok(args)
+        }
+
+        fn merge_config_file(&mut self, file: ConfigFile, matches: &ArgMatches) {
+            fn merge<T>(matches: &ArgMatches, id: &str, target: &mut T, from_file: Option<T>) {
+                if let Some(value) = from_file {
+                    if matches.value_source(id) == Some(ValueSource::DefaultValue) {
+                        *target = value;
+                    }
+                }
+            }
+
+            let ConfigFile {
+                port,
+                client_config,
+                connect_timeout_ms,
+                request_timeout_ms,
+                list_metadata_max_bytes,
+            } = file;
+
+            merge(matches, "port", &mut self.port, port);
+            merge(
+                matches,
+                "client_config",
+                &mut self.client_config,
+                client_config,
+            );
+            merge(
+                matches,
+                "connect_timeout_ms",
+                &mut self.connect_timeout_ms,
+                connect_timeout_ms,
+            );
+            merge(
+                matches,
+                "request_timeout_ms",
+                &mut self.request_timeout_ms,
+                request_timeout_ms,
+            );
+            merge(
+                matches,
+                "list_metadata_max_bytes",
+                &mut self.list_metadata_max_bytes,
+                list_metadata_max_bytes,
+            );
+        }
+
+        /// `version` is the explorer version, which is reported to Iroha in `User-Agent`.
+        /// It should be the one reported by `/info`, i.e. [`version()`].
+        pub fn http_client_config(&self, version: &str) -> HttpClientConfig {
+            HttpClientConfig {
+                connect_timeout: Duration::from_millis(self.connect_timeout_ms),
+                request_timeout: Duration::from_millis(self.request_timeout_ms),
+                user_agent: user_agent(version),
+            }
+        }
+
+        /// Log level requested with `--quiet`/`--verbose` flags, if any
+        pub fn log_level(&self) -> Option<LevelFilter> {
+            match (self.quiet, self.verbose) {
+                (0, 0) => None,
+                (0, 1) => Some(LevelFilter::DEBUG),
+                (0, _) => Some(LevelFilter::TRACE),
+                (1, _) => Some(LevelFilter::WARN),
+                (_, _) => Some(LevelFilter::ERROR),
+            }
+        }
+    }
+
+    /// Settings loaded with `--config`. Each field corresponds to the flag of the same name.
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct ConfigFile {
+        port: Option<u16>,
+        client_config: Option<String>,
+        connect_timeout_ms: Option<u64>,
+        request_timeout_ms: Option<u64>,
+        list_metadata_max_bytes: Option<usize>,
+    }
+
+    impl ConfigFile {
+        fn load(file: &str) -> Result<Self> {
+            let contents = std::fs::read_to_string(file)
+                .wrap_err_with(|| format!("failed to read config file: {file}"))
+                .with_suggestion(|| "try to specify another file with `--config` argument")?;
+            serde_json::from_str(&contents)
+                .wrap_err_with(|| format!("failed to parse config file: {file}"))
         }
     }
 
@@ -82,6 +285,131 @@ mod args {
             Self::new(&cfg)
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn log_level_of(args: &[&str]) -> Option<LevelFilter> {
+            Args::try_parse_from(std::iter::once("explorer").chain(args.iter().copied()))
+                .unwrap()
+                .log_level()
+        }
+
+        #[test]
+        fn no_verbosity_flags_keep_env_default() {
+            assert_eq!(log_level_of(&[]), None);
+        }
+
+        #[test]
+        fn verbosity_flags_map_to_levels() {
+            assert_eq!(log_level_of(&["-q"]), Some(LevelFilter::WARN));
+            assert_eq!(log_level_of(&["-qq"]), Some(LevelFilter::ERROR));
+            assert_eq!(log_level_of(&["--verbose"]), Some(LevelFilter::DEBUG));
+            assert_eq!(log_level_of(&["-vvv"]), Some(LevelFilter::TRACE));
+        }
+
+        #[test]
+        fn quiet_conflicts_with_verbose() {
+            let _err = Args::try_parse_from(["explorer", "-q", "-v"]).unwrap_err();
+        }
+
+        /// Temporary config file, removed on drop
+        struct TempConfigFile(std::path::PathBuf);
+
+        impl TempConfigFile {
+            fn path(&self) -> &str {
+                self.0.to_str().unwrap()
+            }
+        }
+
+        impl Drop for TempConfigFile {
+            fn drop(&mut self) {
+                let _ = std::fs::remove_file(&self.0);
+            }
+        }
+
+        /// Writes `contents` into a temporary config file, unique for the `test_name`
+        /// and the test process
+        fn write_config_file(test_name: &str, contents: &str) -> TempConfigFile {
+            let path = std::env::temp_dir().join(format!(
+                "explorer_config_{}_{test_name}.json",
+                std::process::id()
+            ));
+            std::fs::write(&path, contents).unwrap();
+            TempConfigFile(path)
+        }
+
+        fn args_with_config(config: &str, args: &[&str]) -> Result<Args> {
+            let matches = Args::command().get_matches_from(
+                ["explorer", "--config", config]
+                    .into_iter()
+                    .chain(args.iter().copied()),
+            );
+            Args::from_matches(&matches)
+        }
+
+        #[test]
+        fn config_file_values_are_applied() {
+            let config = write_config_file(
+                "applied",
+                r#"{ "port": 5000, "request_timeout_ms": 1000, "list_metadata_max_bytes": 10 }"#,
+            );
+
+            let args = args_with_config(config.path(), &[]).unwrap();
+
+            assert_eq!(args.port, 5000);
+            assert_eq!(args.request_timeout_ms, 1000);
+            assert_eq!(args.list_metadata_max_bytes, 10);
+            assert_eq!(args.connect_timeout_ms, 5000);
+        }
+
+        #[test]
+        fn flags_override_config_file() {
+            let config = write_config_file(
+                "overridden",
+                r#"{ "port": 5000, "request_timeout_ms": 1000 }"#,
+            );
+
+            let args = args_with_config(config.path(), &["--request-timeout-ms", "2000"]).unwrap();
+
+            assert_eq!(args.port, 5000);
+            assert_eq!(args.request_timeout_ms, 2000);
+        }
+
+        #[test]
+        fn user_agent_reports_given_version() {
+            let args = Args::from_matches(&Args::command().get_matches_from(["explorer"])).unwrap();
+
+            let config = args.http_client_config("1.2.3-custom");
+
+            assert_eq!(config.user_agent, "iroha2_explorer_web/1.2.3-custom");
+        }
+
+        #[test]
+        fn unknown_config_file_field_is_rejected() {
+            let config = write_config_file("unknown", r#"{ "prot": 5000 }"#);
+
+            let _err = args_with_config(config.path(), &[]).unwrap_err();
+        }
+
+        #[test]
+        fn version_falls_back_to_crate_version() {
+            assert_eq!(version_or_default(None), env!("CARGO_PKG_VERSION"));
+        }
+
+        #[test]
+        fn version_override_is_reported() {
+            let version = version_or_default(Some("1.2.3-custom".to_owned()));
+            let err = Args::command()
+                .version(version.as_str())
+                .try_get_matches_from(["explorer", "--version"])
+                .unwrap_err();
+
+            assert_eq!(err.kind(), clap::error::ErrorKind::DisplayVersion);
+            assert!(err.to_string().contains("1.2.3-custom"));
+        }
+    }
 }
 
 /// Web-specific logic - server initialization, endpoints, DTOs etc
@@ -97,29 +425,106 @@ use std::sync::Arc;
 
 use color_eyre::{eyre::WrapErr, Result};
 use iroha_client::client::Client as IrohaClient;
+use iroha_client_wrap::IrohaClientWrap;
+
+/// Checks that Iroha is reachable, logging a warning if it is not. Startup isn't failed
+/// then, because Iroha may become reachable later.
+///
+/// Returns whether Iroha is reachable.
+async fn probe_iroha(client: &IrohaClientWrap) -> bool {
+    match client.get_status().await {
+        Ok(_) => true,
+        Err(err) => {
+            logger::warn!("Iroha is not reachable, requests will fail until it is: {err:#}");
+            false
+        }
+    }
+}
 
 #[actix_web::main]
 async fn main() -> Result<()> {
-    let args = args::Args::parse();
+    let args = args::Args::parse()?;
     let client_config = args::ArgsClientConfig::load(&args)?;
     let account_id = client_config.0.account_id.clone();
+    let version = args::version();
+    let info = web::InfoDTO::new(version.clone(), client_config.0.torii_api_url.to_string());
 
     let client: IrohaClient = client_config
         .try_into()
         .wrap_err("Failed to construct Iroha Client")?;
     let client = Arc::new(client);
+    let http_config = args.http_client_config(&version);
 
     #[cfg(feature = "dev_actor")]
     let _dev_actor = if args.dev_actor {
-        Some(dev_actor::DevActor::start(client.clone(), account_id))
+        Some(dev_actor::DevActor::start(
+            client.clone(),
+            &http_config,
+            account_id,
+        ))
     } else {
         None
     };
 
-    logger::setup();
+    logger::setup(args.log_level());
+
+    probe_iroha(&IrohaClientWrap::new(client.clone(), &http_config)).await;
+
     logger::info!("Server is going to listen on {}", args.port);
 
-    web::server(web::ServerInitData::new(client.clone()), args.port)?
-        .await
-        .wrap_err("Server run failed")
+    web::server(
+        web::ServerInitData::new(
+            client.clone(),
+            http_config,
+            args.list_metadata_max_bytes,
+            info,
+        ),
+        args.port,
+    )?
+    .await
+    .wrap_err("Server run failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iroha_client_wrap::{
+        test_utils::{client, free_port, unreachable_url},
+        HttpClientConfig,
+    };
+    use std::time::Duration;
+
+    #[actix_web::test]
+    async fn server_starts_when_iroha_is_unreachable() {
+        let torii_url = unreachable_url();
+        let iroha_client = client(&torii_url);
+        let http_config = HttpClientConfig {
+            connect_timeout: Duration::from_millis(500),
+            request_timeout: Duration::from_millis(500),
+            ..HttpClientConfig::default()
+        };
+
+        assert!(!probe_iroha(&IrohaClientWrap::new(iroha_client.clone(), &http_config)).await);
+
+        let port = free_port();
+        let server = web::server(
+            web::ServerInitData::new(
+                iroha_client,
+                http_config,
+                4096,
+                web::InfoDTO::new("0.1.0".to_owned(), torii_url),
+            ),
+            port,
+        )
+        .unwrap();
+        actix_web::rt::spawn(server);
+
+        let resp = awc::Client::new()
+            .get(format!("http://127.0.0.1:{port}/api/v1"))
+            .send()
+            .await
+            .unwrap();
+
+        assert!(resp.status().is_success());
+    }
 }
@@ -4,21 +4,33 @@ use super::{
     etc::{HashDeser, SerScaleHex, Timestamp},
     get,
     pagination::{Paginated, PaginationQueryParams},
-    web, AppData, Scope, WebError,
+    transactions::TransactionDTO,
+    web, AppData, ETagged, Json, Scope, WebError,
 };
 use color_eyre::{
     eyre::{eyre, Context},
     Result,
 };
-use iroha_core::tx::{Pagination, VersionedSignedTransaction};
+use iroha_core::tx::{Executable, Pagination, VersionedSignedTransaction};
 use iroha_crypto::{Hash, HashOf, MerkleTree};
 use iroha_data_model::{
     block::VersionedCommittedBlock,
     prelude::{FindAllBlocks, TransactionValue},
 };
 
-use serde::Serialize;
-use std::{convert::TryInto, num::NonZeroU64};
+use serde::{Deserialize, Serialize};
+use std::{
+    convert::TryInto,
+    num::{NonZeroU32, NonZeroU64},
+};
+
+/// Max amount of hashes that may be requested from `/blocks/recent-hashes`
+const RECENT_HASHES_MAX: u32 = 256;
+
+const DEFAULT_RECENT_HASHES: NonZeroU32 = match NonZeroU32::new(16) {
+    Some(v) => v,
+    None => panic!("Failed to make default amount of recent hashes"),
+};
 
 /// Block DTO intended to be lightweight and to have only simple aggregated data.
 /// Detailed data is contained within [`BlockDTO`]
@@ -64,6 +76,54 @@ pub struct BlockDTO {
     transactions: Vec<SerScaleHex<TransactionValue>>,
     rejected_transactions: Vec<SerScaleHex<VersionedSignedTransaction>>,
     view_change_proofs: Vec<SerScaleHex<Hash>>,
+    /// Total amount of instructions in the block's transactions. `None` if some of them
+    /// are WASM smart contracts, whose instructions can't be counted.
+    instructions_total: Option<u32>,
+    executables: ExecutableCountsDTO,
+}
+
+/// Amounts of transactions by the kind of their executable
+#[derive(Serialize, Default, Debug, PartialEq, Eq)]
+pub struct ExecutableCountsDTO {
+    instructions: u32,
+    wasm: u32,
+}
+
+/// Counts instructions and executables of a block's transactions.
+/// See [`BlockDTO`]'s `instructions_total` and `executables`.
+fn count_executables<'a>(
+    executables: impl IntoIterator<Item = &'a Executable>,
+) -> Result<(Option<u32>, ExecutableCountsDTO)> {
+    let mut instructions_total = Some(0u32);
+    let mut counts = ExecutableCountsDTO::default();
+
+    for executable in executables {
+        match executable {
+            Executable::Instructions(items) => {
+                counts.instructions = counts
+                    .instructions
+                    .checked_add(1)
+                    .ok_or_else(|| eyre!("Count of instruction executables overflows u32"))?;
+                let len: u32 = items.len().try_into()?;
+                if let Some(total) = instructions_total {
+                    instructions_total = Some(
+                        total
+                            .checked_add(len)
+                            .ok_or_else(|| eyre!("Total of instructions overflows u32"))?,
+                    );
+                }
+            }
+            Executable::Wasm(_) => {
+                counts.wasm = counts
+                    .wasm
+                    .checked_add(1)
+                    .ok_or_else(|| eyre!("Count of WASM executables overflows u32"))?;
+                instructions_total = None;
+            }
+        }
+    }
+
+    Ok((instructions_total, counts))
 }
 
 impl TryFrom<VersionedCommittedBlock> for BlockDTO {
@@ -71,6 +131,14 @@ impl TryFrom<VersionedCommittedBlock> for BlockDTO {
 
     fn try_from(block: VersionedCommittedBlock) -> Result<Self> {
         let block = block.into_v1();
+        let (instructions_total, executables) = count_executables(
+            block
+                .transactions
+                .iter()
+                .map(|TransactionValue { tx, .. }| &tx.payload().instructions),
+        )
+        .wrap_err("Failed to count executables")?;
+
         Ok(Self {
             height: block.header.height.try_into()?,
             timestamp: Timestamp::try_from(block.header.timestamp)?,
@@ -86,15 +154,106 @@ impl TryFrom<VersionedCommittedBlock> for BlockDTO {
 
             // FIXME https://github.com/hyperledger/iroha/issues/2277
             view_change_proofs: Vec::new(),
+            instructions_total,
+            executables,
         })
     }
 }
 
-#[get("/{height_or_hash}")]
-async fn show(
+/// Height and hash of a block, without its contents
+#[derive(Serialize)]
+pub struct BlockHashDTO {
+    height: u32,
+    block_hash: SerScaleHex<Hash>,
+}
+
+impl TryFrom<VersionedCommittedBlock> for BlockHashDTO {
+    type Error = color_eyre::Report;
+
+    fn try_from(block: VersionedCommittedBlock) -> Result<Self> {
+        let block = block.into_v1();
+        Ok(Self {
+            height: block.header.height.try_into()?,
+            block_hash: block.hash().into(),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RecentHashesQueryParams {
+    #[serde(default = "default_recent_hashes")]
+    n: NonZeroU32,
+}
+
+const fn default_recent_hashes() -> NonZeroU32 {
+    DEFAULT_RECENT_HASHES
+}
+
+/// Hashes of the last `n` blocks, the latest first. Lets clients check that they are
+/// on the same chain as the explorer.
+///
+/// The hashes may lag behind the chain a bit, see [`latest_blocks`].
+#[get("/recent-hashes")]
+async fn recent_hashes(
     app: web::Data<AppData>,
+    web::Query(RecentHashesQueryParams { n }): web::Query<RecentHashesQueryParams>,
+) -> Result<Json<Vec<BlockHashDTO>>, WebError> {
+    let n = n.get();
+    if n > RECENT_HASHES_MAX {
+        return Err(WebError::bad_request(format!(
+            "`n` ({n}) must not exceed {RECENT_HASHES_MAX}"
+        )));
+    }
+
+    let hashes = latest_blocks(&app, n)
+        .await?
+        .into_iter()
+        .rev()
+        .map(TryInto::try_into)
+        .collect::<Result<Vec<BlockHashDTO>>>()
+        .wrap_err("Failed to construct BlockHashDTO")?;
+
+    Ok(Json(hashes))
+}
+
+/// Fetches the last `n` blocks, the oldest first
+///
+/// Iroha can't return the last blocks in a single query, so first the height is queried,
+/// and then the blocks below it. Blocks committed in between are not included, i.e. the
+/// result may lag behind the chain by a few blocks. It is never a mix of blocks that
+/// weren't the latest at the same moment, though, because committed blocks never change.
+pub async fn latest_blocks(
+    app: &AppData,
+    n: u32,
+) -> Result<Vec<VersionedCommittedBlock>, WebError> {
+    let total = app
+        .iroha_client
+        .request(QueryBuilder::new(FindAllBlocks).with_pagination(Pagination::new(None, Some(1))))
+        .await
+        .map_err(WebError::expect_iroha_any_error)?
+        .total;
+    let start: u32 = total
+        .saturating_sub(u64::from(n))
+        .try_into()
+        .wrap_err("Failed to convert blocks offset")?;
+
+    let blocks = app
+        .iroha_client
+        .request(
+            QueryBuilder::new(FindAllBlocks).with_pagination(Pagination::new(Some(start), Some(n))),
+        )
+        .await
+        .map_err(WebError::expect_iroha_any_error)?
+        .only_output();
+
+    Ok(blocks)
+}
+
+/// Fetches a block by its height or hash. Responds with [`WebError::NotFound`] if there is no such block.
+async fn find_block(
+    app: &AppData,
     block_id: web::Either<web::Path<NonZeroU64>, web::Path<HashDeser>>,
-) -> Result<web::Json<BlockDTO>, WebError> {
+) -> Result<VersionedCommittedBlock, WebError> {
     match block_id {
         web::Either::Left(height) => {
             let height = height.into_inner();
@@ -114,15 +273,10 @@ async fn show(
                 .map_err(WebError::expect_iroha_any_error)?
                 .only_output();
 
-            let block = match blocks.len() {
-                0 => return Err(WebError::NotFound),
-                1 => blocks.into_iter().next().expect("Blocks len should be 1"),
-                x => return Err(eyre!("Expected to get 0 or 1 block, got: {x}").into()),
-            };
-
-            Ok(web::Json(
-                block.try_into().wrap_err("Failed to construct BlockDTO")?,
-            ))
+            if blocks.len() > 1 {
+                return Err(eyre!("Expected to get 0 or 1 block, got: {}", blocks.len()).into());
+            }
+            blocks.into_iter().next().ok_or(WebError::NotFound)
         }
         web::Either::Right(_hash) => Err(WebError::not_implemented(
             "Fetching block by hash is not yet implemented".to_string(),
@@ -130,17 +284,51 @@ async fn show(
     }
 }
 
+/// Committed blocks never change, so the response is tagged with the block hash
+#[get("/{height_or_hash}")]
+async fn show(
+    app: web::Data<AppData>,
+    block_id: web::Either<web::Path<NonZeroU64>, web::Path<HashDeser>>,
+) -> Result<ETagged<Json<BlockDTO>>, WebError> {
+    let block = find_block(&app, block_id).await?;
+    let block: BlockDTO = block.try_into().wrap_err("Failed to construct BlockDTO")?;
+
+    Ok(ETagged::new(block.block_hash.0, Json(block)))
+}
+
+/// Transactions of a single block. They are paginated by the explorer itself,
+/// because the block is fetched as a whole anyway.
+#[get("/{height_or_hash}/transactions")]
+async fn transactions(
+    app: web::Data<AppData>,
+    block_id: web::Either<web::Path<NonZeroU64>, web::Path<HashDeser>>,
+    pagination: web::Query<PaginationQueryParams>,
+) -> Result<Paginated<Vec<TransactionDTO>>, WebError> {
+    let block = find_block(&app, block_id).await?.into_v1();
+    let block_hash = block.hash();
+
+    let paginated = Paginated::from_whole(block.transactions, pagination.into_inner())?;
+    let data = paginated
+        .data
+        .into_iter()
+        .map(|tx| TransactionDTO::from_block_value(tx, block_hash))
+        .collect::<Result<Vec<_>>>()
+        .wrap_err("Failed to construct TransactionDTO")?;
+
+    Ok(Paginated::new(data, paginated.pagination))
+}
+
 #[get("")]
 async fn index(
     app: web::Data<AppData>,
     pagination: web::Query<PaginationQueryParams>,
-) -> Result<web::Json<Paginated<Vec<BlockShallowDTO>>>, WebError> {
+) -> Result<Paginated<Vec<BlockShallowDTO>>, WebError> {
     let Paginated {
         data: blocks,
         pagination,
     } = app
         .iroha_client
-        .request(QueryBuilder::new(FindAllBlocks).with_pagination(pagination.0.into()))
+        .request(QueryBuilder::new(FindAllBlocks).with_pagination(pagination.0.try_into()?))
         .await
         .map_err(WebError::expect_iroha_any_error)?
         .try_into()?;
@@ -151,9 +339,114 @@ async fn index(
         .collect::<Result<Vec<BlockShallowDTO>>>()
         .wrap_err("Failed to construct BlockShallowDTO")?;
 
-    Ok(web::Json(Paginated::new(blocks, pagination)))
+    Ok(Paginated::new(blocks, pagination))
 }
 
 pub fn scope() -> Scope {
-    web::scope("/blocks").service(index).service(show)
+    web::scope("/blocks")
+        .service(index)
+        // before `show`, so that `recent-hashes` is not treated as a block hash
+        .service(recent_hashes)
+        .service(show)
+        .service(transactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{iroha_client_wrap::test_utils::unreachable_url, web::call_scope};
+    use actix_web::http::StatusCode;
+    use iroha_data_model::prelude::FailBox;
+
+    fn instructions(len: usize) -> Executable {
+        Executable::Instructions(
+            std::iter::repeat_with(|| FailBox::new("fail").into())
+                .take(len)
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn instructions_are_counted() {
+        let executables = [instructions(2), instructions(0), instructions(3)];
+
+        let (instructions_total, counts) = count_executables(&executables).unwrap();
+
+        assert_eq!(instructions_total, Some(5));
+        assert_eq!(
+            counts,
+            ExecutableCountsDTO {
+                instructions: 3,
+                wasm: 0
+            }
+        );
+    }
+
+    #[test]
+    fn no_transactions() {
+        let (instructions_total, counts) = count_executables(&[]).unwrap();
+
+        assert_eq!(instructions_total, Some(0));
+        assert_eq!(counts, ExecutableCountsDTO::default());
+    }
+
+    #[actix_web::test]
+    async fn zero_height_is_bad_request() {
+        assert_eq!(
+            call_scope(scope(), &unreachable_url(), "/blocks/0")
+                .await
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            call_scope(scope(), &unreachable_url(), "/blocks/0/transactions")
+                .await
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn height_beyond_u64_is_bad_request() {
+        assert_eq!(
+            call_scope(scope(), &unreachable_url(), "/blocks/18446744073709551616")
+                .await
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn page_beyond_u32_is_bad_request() {
+        assert_eq!(
+            call_scope(scope(), &unreachable_url(), "/blocks?page=4294967296")
+                .await
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn page_offset_beyond_u32_is_bad_request() {
+        assert_eq!(
+            call_scope(
+                scope(),
+                &unreachable_url(),
+                "/blocks?page=4294967295&page_size=2"
+            )
+            .await
+            .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn too_many_recent_hashes_is_bad_request() {
+        assert_eq!(
+            call_scope(scope(), &unreachable_url(), "/blocks/recent-hashes?n=257")
+                .await
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
 }
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use color_eyre::eyre::ContextCompat;
 use color_eyre::Result;
 use iroha_crypto::{Hash, HashOf, PublicKey, Signature};
+use iroha_data_model::prelude::Metadata;
 use parity_scale_codec::Encode;
 use serde::{de, Serialize};
 use std::{fmt, marker::PhantomData};
@@ -189,9 +190,53 @@ impl From<Signature> for SignatureDTO {
     }
 }
 
+/// Metadata along with a marker whether it was truncated. Meant to be flattened into
+/// a DTO, so that it serializes into `metadata` and `metadata_truncated` fields.
+///
+/// List responses limit metadata size with [`MetadataDTO::limit`] to not become huge,
+/// while `show` responses contain full metadata.
+#[derive(Serialize)]
+pub struct MetadataDTO {
+    metadata: Metadata,
+    metadata_truncated: bool,
+}
+
+impl From<Metadata> for MetadataDTO {
+    fn from(metadata: Metadata) -> Self {
+        Self {
+            metadata,
+            metadata_truncated: false,
+        }
+    }
+}
+
+impl MetadataDTO {
+    /// Replaces metadata with an empty one if its JSON size exceeds `max_bytes`
+    #[must_use]
+    pub fn limit(self, max_bytes: usize) -> Self {
+        let exceeds =
+            serde_json::to_vec(&self.metadata).map_or(true, |json| json.len() > max_bytes);
+
+        if exceeds {
+            Self {
+                metadata: Metadata::new(),
+                metadata_truncated: true,
+            }
+        } else {
+            self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{SerScaleHex, Timestamp};
+    use super::{HashDeser, MetadataDTO, SerScaleHex, StringOf, Timestamp};
+    use iroha_crypto::Hash;
+    use iroha_data_model::{
+        metadata::Limits as MetadataLimits,
+        prelude::{AccountId, AssetDefinitionId, DomainId, Metadata, Value},
+    };
+    use std::{fmt::Display, str::FromStr};
 
     // TODO move to doctest when possible
     #[test]
@@ -217,4 +262,97 @@ mod tests {
 
         assert_eq!(wrap_json, sample_num_expected_json);
     }
+
+    /// Parses `input` as `T`, wraps into [`StringOf`] and asserts that it serializes back into `input`
+    fn assert_string_of_round_trip<T>(input: &str)
+    where
+        T: FromStr + Display,
+        <T as FromStr>::Err: std::fmt::Debug,
+    {
+        let value: T = input.parse().unwrap();
+        let json = serde_json::to_string(&StringOf::from(value)).unwrap();
+
+        assert_eq!(json, serde_json::to_string(input).unwrap());
+    }
+
+    #[test]
+    fn domain_id_canonical_form() {
+        assert_string_of_round_trip::<DomainId>("wonderland");
+    }
+
+    #[test]
+    fn account_id_canonical_form() {
+        assert_string_of_round_trip::<AccountId>("alice@wonderland");
+    }
+
+    #[test]
+    fn asset_definition_id_canonical_form() {
+        assert_string_of_round_trip::<AssetDefinitionId>("rose#wonderland");
+    }
+
+    #[test]
+    fn hash_round_trip_is_lowercase() {
+        // The last byte is odd, so it is not altered by `Hash::prehashed`
+        let lowercase = "ab".repeat(Hash::LENGTH);
+        let uppercase = lowercase.to_uppercase();
+
+        for input in [&lowercase, &uppercase] {
+            let HashDeser(hash) =
+                serde_json::from_str(&serde_json::to_string(input).unwrap()).unwrap();
+            let json = serde_json::to_string(&SerScaleHex(hash)).unwrap();
+
+            assert_eq!(json, serde_json::to_string(&lowercase).unwrap());
+        }
+    }
+
+    #[test]
+    fn hash_of_wrong_length_is_rejected() {
+        let input = serde_json::to_string(&"ab".repeat(Hash::LENGTH - 1)).unwrap();
+
+        let _err = serde_json::from_str::<HashDeser>(&input).unwrap_err();
+    }
+
+    fn metadata_with_blob(len: usize) -> Metadata {
+        let mut metadata = Metadata::new();
+        metadata
+            .insert_with_limits(
+                "blob".parse().unwrap(),
+                Value::String("x".repeat(len)),
+                MetadataLimits::new(1, u32::MAX),
+            )
+            .unwrap();
+        metadata
+    }
+
+    #[test]
+    fn large_metadata_is_truncated() {
+        let dto = MetadataDTO::from(metadata_with_blob(10_000)).limit(1_000);
+        let json = serde_json::to_value(&dto).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({ "metadata": {}, "metadata_truncated": true })
+        );
+    }
+
+    #[test]
+    fn small_metadata_is_kept() {
+        let dto = MetadataDTO::from(metadata_with_blob(10)).limit(1_000);
+        let json = serde_json::to_value(&dto).unwrap();
+
+        assert_eq!(json["metadata"]["blob"], serde_json::json!("x".repeat(10)));
+        assert_eq!(json["metadata_truncated"], false);
+    }
+
+    #[test]
+    fn metadata_is_full_unless_limited() {
+        let dto = MetadataDTO::from(metadata_with_blob(10_000));
+        let json = serde_json::to_value(&dto).unwrap();
+
+        assert_eq!(
+            json["metadata"]["blob"],
+            serde_json::json!("x".repeat(10_000))
+        );
+        assert_eq!(json["metadata_truncated"], false);
+    }
 }
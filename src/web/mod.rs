@@ -1,12 +1,18 @@
-use crate::iroha_client_wrap::{IrohaClientWrap, QueryBuilder};
+use crate::iroha_client_wrap::{HttpClientConfig, IrohaClientWrap, QueryBuilder};
 use actix_web::{
-    error::ResponseError, get, http, middleware, web, App, HttpResponse, HttpServer, Responder,
-    Scope,
+    body::BoxBody,
+    dev::{Service, ServiceRequest},
+    error::{PathError, QueryPayloadError, ResponseError},
+    get, http,
+    http::header::{ETag, EntityTag, Header, IfNoneMatch},
+    middleware, web, App, HttpRequest, HttpResponse, HttpServer, Responder, Scope,
 };
 use color_eyre::eyre::{eyre, Context};
 use iroha_client::client::ClientQueryError as IrohaClientQueryError;
+use iroha_crypto::Hash;
 use pagination::{Paginated, PaginationQueryParams};
-use serde::Serialize;
+use parity_scale_codec::Encode;
+use serde::{Deserialize, Serialize};
 use std::{
     fmt::{self, Debug},
     str::FromStr,
@@ -21,17 +27,52 @@ mod transactions;
 pub struct AppData {
     /// Pre-initialized Iroha Client
     iroha_client: IrohaClientWrap,
+    /// Max JSON size of an entity metadata in list responses. See [`etc::MetadataDTO`]
+    list_metadata_max_bytes: usize,
 }
 
 impl AppData {
     /// Creates new state with provided client
-    pub fn new(client: IrohaClientWrap) -> Self {
+    pub fn new(client: IrohaClientWrap, list_metadata_max_bytes: usize) -> Self {
         Self {
             iroha_client: client,
+            list_metadata_max_bytes,
         }
     }
 }
 
+#[cfg(test)]
+impl AppData {
+    /// State with a client of Iroha at `torii_url` and default settings
+    fn for_tests(torii_url: &str) -> Self {
+        Self::new(
+            IrohaClientWrap::new(
+                crate::iroha_client_wrap::test_utils::client(torii_url),
+                &HttpClientConfig::default(),
+            ),
+            4096,
+        )
+    }
+}
+
+/// Requests `uri` from an app serving `scope` with the same error handlers as [`server`].
+/// The app queries Iroha at `torii_url`.
+#[cfg(test)]
+async fn call_scope(scope: Scope, torii_url: &str, uri: &str) -> actix_web::dev::ServiceResponse {
+    use actix_web::test;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(AppData::for_tests(torii_url)))
+            .app_data(web::QueryConfig::default().error_handler(query_error_handler))
+            .app_data(web::PathConfig::default().error_handler(path_error_handler))
+            .service(scope),
+    )
+    .await;
+
+    test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await
+}
+
 /// General error for all endpoints
 #[derive(Debug, thiserror::Error)]
 enum WebError {
@@ -42,6 +83,9 @@ enum WebError {
     /// Some resource was not found.
     #[error("Not Found")]
     NotFound,
+    /// Some resource was not found. Contains a message for the client, explaining what exactly is missing.
+    #[error("Not Found: {message_to_client}")]
+    NotFoundWithMessage { message_to_client: String },
     /// Client made a bad request. Contains a message for the client.
     #[error("Bad Request: {message_to_client}")]
     BadRequest { message_to_client: String },
@@ -75,6 +119,10 @@ impl WebError {
         }
     }
 
+    fn not_found(message_to_client: String) -> Self {
+        Self::NotFoundWithMessage { message_to_client }
+    }
+
     fn bad_request(message_to_client: String) -> Self {
         Self::BadRequest { message_to_client }
     }
@@ -94,7 +142,7 @@ impl ResponseError for WebError {
     fn status_code(&self) -> http::StatusCode {
         match self {
             Self::Internal(_) => http::StatusCode::INTERNAL_SERVER_ERROR,
-            Self::NotFound => http::StatusCode::NOT_FOUND,
+            Self::NotFound | Self::NotFoundWithMessage { .. } => http::StatusCode::NOT_FOUND,
             Self::BadRequest { .. } => http::StatusCode::BAD_REQUEST,
             Self::NotImplemented { .. } => http::StatusCode::NOT_IMPLEMENTED,
         }
@@ -113,14 +161,110 @@ impl From<iroha_data_model::ParseError> for WebError {
     }
 }
 
+/// Same as [`web::Json`] responder, but pretty-prints the body if the request has
+/// the `pretty=true` query param. Responses are minified by default.
+pub struct Json<T>(pub T);
+
+#[derive(Deserialize)]
+struct PrettyQueryParam {
+    #[serde(default)]
+    pretty: bool,
+}
+
+impl<T: Serialize> Responder for Json<T> {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse {
+        // Malformed `pretty` is not worth failing the request
+        let pretty = web::Query::<PrettyQueryParam>::from_query(req.query_string())
+            .map_or(false, |query| query.pretty);
+
+        let body = if pretty {
+            serde_json::to_string_pretty(&self.0)
+        } else {
+            serde_json::to_string(&self.0)
+        };
+
+        match body {
+            Ok(body) => HttpResponse::Ok()
+                .content_type(http::header::ContentType::json())
+                .body(body),
+            Err(err) => WebError::Internal(
+                color_eyre::Report::new(err).wrap_err("Failed to serialize response"),
+            )
+            .error_response(),
+        }
+    }
+}
+
+/// Wraps a response about an immutable entity (e.g. a committed block), tagging it with
+/// a weak `ETag` made of the entity hash. If the request has a matching `If-None-Match`,
+/// responds with `304 Not Modified` without a body instead.
+///
+/// The tag is weak because the same entity may be represented differently, e.g. with `pretty=true`.
+pub struct ETagged<R> {
+    etag: EntityTag,
+    inner: R,
+}
+
+impl<R> ETagged<R> {
+    pub fn new(hash: Hash, inner: R) -> Self {
+        Self {
+            etag: EntityTag::new_weak(hex::encode(hash.encode())),
+            inner,
+        }
+    }
+}
+
+impl<R> Responder for ETagged<R>
+where
+    R: Responder,
+    R::Body: 'static,
+{
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse {
+        let not_modified = match IfNoneMatch::parse(req) {
+            Ok(IfNoneMatch::Any) => true,
+            Ok(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&self.etag)),
+            Err(_) => false,
+        };
+
+        if not_modified {
+            return HttpResponse::NotModified()
+                .insert_header(ETag(self.etag))
+                .finish();
+        }
+
+        self.inner
+            .customize()
+            .insert_header(ETag(self.etag))
+            .respond_to(req)
+            .map_into_boxed_body()
+    }
+}
+
+/// Maps query params parsing failures to [`WebError::BadRequest`]
+fn query_error_handler(err: QueryPayloadError, _req: &HttpRequest) -> actix_web::Error {
+    WebError::bad_request(format!("Bad query: {err}")).into()
+}
+
+/// Maps path extraction failures (e.g. malformed ids) to [`WebError::BadRequest`],
+/// so that they are not confused with [`WebError::NotFound`] for well-formed but unknown ids.
+fn path_error_handler(err: PathError, _req: &HttpRequest) -> actix_web::Error {
+    WebError::bad_request(format!("Bad path: {err}")).into()
+}
+
 mod accounts {
     use super::{
-        assets::AssetDTO, etc::StringOf, fmt, get, web, AppData, Context, FromStr, Paginated,
-        PaginationQueryParams, QueryBuilder, Scope, Serialize, WebError,
+        assets::AssetDTO,
+        etc::{MetadataDTO, StringOf},
+        fmt, get, web, AppData, Context, FromStr, Json, Paginated, PaginationQueryParams,
+        QueryBuilder, Scope, Serialize, WebError,
     };
     use iroha_data_model::prelude::{
-        Account, AccountId, FindAccountById, FindAllAccounts, HasMetadata, Identifiable, Metadata,
-        RoleId,
+        Account, AccountId, FindAccountById, FindAllAccounts, FindAssetsByAccountId, HasMetadata,
+        Identifiable, RoleId,
     };
     use serde::de;
 
@@ -129,10 +273,22 @@ mod accounts {
         id: StringOf<AccountId>,
         // FIXME should it be paginated?
         assets: Vec<AssetDTO>,
-        metadata: Metadata,
+        #[serde(flatten)]
+        metadata: MetadataDTO,
         roles: Vec<StringOf<RoleId>>,
     }
 
+    impl AccountDTO {
+        /// See [`MetadataDTO::limit`]
+        #[must_use]
+        pub fn limit_metadata(self, max_bytes: usize) -> Self {
+            Self {
+                metadata: self.metadata.limit(max_bytes),
+                ..self
+            }
+        }
+    }
+
     impl From<Account> for AccountDTO {
         fn from(account: Account) -> Self {
             let assets: Vec<AssetDTO> = account
@@ -148,7 +304,7 @@ mod accounts {
                 assets,
                 metadata:
                 // FIXME clone
-                account.metadata().clone(),
+                account.metadata().clone().into(),
                 roles: account.roles().map(StringOf::from).collect(),
             }
         }
@@ -188,7 +344,7 @@ mod accounts {
     async fn show(
         data: web::Data<AppData>,
         id: web::Path<AccountIdInPath>,
-    ) -> Result<web::Json<AccountDTO>, WebError> {
+    ) -> Result<Json<AccountDTO>, WebError> {
         let account = data
             .iroha_client
             .request(QueryBuilder::new(FindAccountById::new(id.into_inner().0)))
@@ -196,51 +352,106 @@ mod accounts {
             .map_err(WebError::expect_iroha_find_error)?
             .only_output();
 
-        Ok(web::Json(account.into()))
+        Ok(Json(account.into()))
+    }
+
+    #[get("/{id}/assets")]
+    async fn assets(
+        data: web::Data<AppData>,
+        id: web::Path<AccountIdInPath>,
+        web::Query(pagination): web::Query<PaginationQueryParams>,
+    ) -> Result<Paginated<Vec<AssetDTO>>, WebError> {
+        let AccountIdInPath(account_id) = id.into_inner();
+
+        // So that an unknown account is not confused with an account without assets
+        data.iroha_client
+            .request(QueryBuilder::new(FindAccountById::new(account_id.clone())))
+            .await
+            .map_err(WebError::expect_iroha_find_error)?;
+
+        let paginated: Paginated<_> = data
+            .iroha_client
+            .request(
+                QueryBuilder::new(FindAssetsByAccountId::new(account_id))
+                    .with_pagination(pagination.try_into()?),
+            )
+            .await
+            .map_err(WebError::expect_iroha_any_error)?
+            .try_into()?;
+
+        Ok(paginated.map(|assets| assets.into_iter().map(Into::into).collect()))
     }
 
     #[get("")]
     async fn index(
         data: web::Data<AppData>,
         web::Query(pagination): web::Query<PaginationQueryParams>,
-    ) -> Result<web::Json<Paginated<Vec<AccountDTO>>>, WebError> {
+    ) -> Result<Paginated<Vec<AccountDTO>>, WebError> {
         let paginated: Paginated<_> = data
             .iroha_client
-            .request(QueryBuilder::new(FindAllAccounts).with_pagination(pagination.into()))
+            .request(QueryBuilder::new(FindAllAccounts).with_pagination(pagination.try_into()?))
             .await
             .wrap_err("Failed to request for accounts")?
             .try_into()?;
 
-        Ok(web::Json(paginated.map(|accounts| {
-            accounts.into_iter().map(Into::into).collect()
-        })))
+        Ok(paginated.map(|accounts| {
+            accounts
+                .into_iter()
+                .map(|account| {
+                    AccountDTO::from(account).limit_metadata(data.list_metadata_max_bytes)
+                })
+                .collect()
+        }))
     }
 
     pub fn scope() -> Scope {
-        web::scope("/accounts").service(index).service(show)
+        web::scope("/accounts")
+            .service(index)
+            .service(show)
+            .service(assets)
     }
 }
 
 mod domains {
     use super::{
-        accounts::AccountDTO, asset_definitions::AssetDefinitionDTO, etc::StringOf, get, web,
-        AppData, Paginated, PaginationQueryParams, QueryBuilder, Scope, Serialize, WebError,
+        accounts::AccountDTO,
+        asset_definitions::AssetDefinitionDTO,
+        etc::{MetadataDTO, StringOf},
+        fmt, get, web, AppData, FromStr, Json, Paginated, PaginationQueryParams, QueryBuilder,
+        Scope, Serialize, WebError,
     };
     use iroha_data_model::prelude::{
-        Domain, DomainId, FindAllDomains, FindDomainById, Identifiable, Metadata,
+        Domain, DomainId, FindAllDomains, FindDomainById, Identifiable,
     };
+    use serde::{de, Deserialize};
 
     #[derive(Serialize)]
     struct DomainDTO {
         id: StringOf<DomainId>,
         accounts: Vec<AccountDTO>,
         logo: Option<String>,
-        metadata: Metadata,
+        #[serde(flatten)]
+        metadata: MetadataDTO,
         asset_definitions: Vec<AssetDefinitionDTO>,
         // FIXME https://github.com/hyperledger/iroha/issues/2302
         triggers: u32,
     }
 
+    impl DomainDTO {
+        /// Limits metadata of the domain and of its accounts. See [`MetadataDTO::limit`]
+        fn limit_metadata(self, max_bytes: usize) -> Self {
+            Self {
+                accounts: self
+                    .accounts
+                    .into_iter()
+                    .map(|account| account.limit_metadata(max_bytes))
+                    .collect(),
+                metadata: self.metadata.limit(max_bytes),
+                ..self
+            }
+        }
+    }
+
     impl From<Domain> for DomainDTO {
         fn from(domain: Domain) -> Self {
             Self {
@@ -252,7 +463,7 @@ mod domains {
                         AccountDTO::from(acc.clone()))
                     .collect(),
                 logo: domain.logo().as_ref().map(|x| x.as_ref().to_owned()),
-                metadata: domain.metadata.clone(), // FIXME clone
+                metadata: domain.metadata.clone().into(), // FIXME clone
                 asset_definitions: AssetDefinitionDTO::vec_from_map(
                     domain
                         // FIXME clone
@@ -264,52 +475,176 @@ mod domains {
         }
     }
 
+    pub struct DomainIdInPath(pub DomainId);
+
+    impl<'de> de::Deserialize<'de> for DomainIdInPath {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct Visitor;
+
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = DomainIdInPath;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, "a string in a format `wonderland`")
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    DomainId::from_str(v)
+                        .map(DomainIdInPath)
+                        .map_err(|_parse_error| E::invalid_value(de::Unexpected::Str(v), &self))
+                }
+            }
+
+            deserializer.deserialize_string(Visitor)
+        }
+    }
+
     #[get("/{id}")]
     async fn show(
         data: web::Data<AppData>,
-        path: web::Path<String>,
-    ) -> Result<web::Json<DomainDTO>, WebError> {
-        let domain_id: DomainId = path.into_inner().parse()?;
+        id: web::Path<DomainIdInPath>,
+    ) -> Result<Json<DomainDTO>, WebError> {
+        let DomainIdInPath(domain_id) = id.into_inner();
         let domain = data
             .iroha_client
             .request(QueryBuilder::new(FindDomainById::new(domain_id)))
             .await
             .map_err(WebError::expect_iroha_find_error)?
             .only_output();
-        Ok(web::Json(DomainDTO::from(domain)))
+        Ok(Json(DomainDTO::from(domain)))
+    }
+
+    #[derive(Deserialize)]
+    pub struct DomainsQueryParams {
+        /// Substring of domain ids to search for, case-insensitively
+        search: Option<String>,
+    }
+
+    /// Whether `id` contains `search`, ignoring case
+    fn id_matches(id: &DomainId, search: &str) -> bool {
+        id.to_string()
+            .to_lowercase()
+            .contains(&search.to_lowercase())
     }
 
     #[get("")]
     async fn index(
         data: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
-    ) -> Result<web::Json<Paginated<Vec<DomainDTO>>>, WebError> {
-        let paginated: Paginated<_> = data
-            .iroha_client
-            .request(
-                QueryBuilder::new(FindAllDomains).with_pagination(pagination.into_inner().into()),
-            )
-            .await
-            .map_err(WebError::expect_iroha_any_error)?
-            .try_into()?;
-        Ok(web::Json(paginated.map(|domains| {
-            domains.into_iter().map(Into::into).collect()
-        })))
+        web::Query(DomainsQueryParams { search }): web::Query<DomainsQueryParams>,
+    ) -> Result<Paginated<Vec<DomainDTO>>, WebError> {
+        let paginated: Paginated<Vec<Domain>> = match search {
+            // Iroha string predicates are case-sensitive, so searched domains are
+            // filtered and paginated by the explorer itself
+            Some(search) => {
+                let domains = data
+                    .iroha_client
+                    .request(QueryBuilder::new(FindAllDomains))
+                    .await
+                    .map_err(WebError::expect_iroha_any_error)?
+                    .only_output()
+                    .into_iter()
+                    .filter(|domain| id_matches(domain.id(), &search))
+                    .collect();
+                Paginated::from_whole(domains, pagination.into_inner())?
+            }
+            None => data
+                .iroha_client
+                .request(
+                    QueryBuilder::new(FindAllDomains)
+                        .with_pagination(pagination.into_inner().try_into()?),
+                )
+                .await
+                .map_err(WebError::expect_iroha_any_error)?
+                .try_into()?,
+        };
+        Ok(paginated.map(|domains| {
+            domains
+                .into_iter()
+                .map(|domain| DomainDTO::from(domain).limit_metadata(data.list_metadata_max_bytes))
+                .collect()
+        }))
     }
 
     pub fn scope() -> Scope {
         web::scope("/domains").service(index).service(show)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{
+            iroha_client_wrap::test_utils::{domain, scripted_torii_url, unreachable_url},
+            web::call_scope,
+        };
+        use actix_web::{http::StatusCode, test};
+        use iroha_data_model::prelude::Value;
+
+        #[actix_web::test]
+        async fn search_ignores_case_and_is_applied_before_pagination() {
+            let torii_url = scripted_torii_url(vec![Ok(Value::Vec(vec![
+                domain("wonderland"),
+                domain("garden_of_live_flowers"),
+                domain("looking_glass"),
+                domain("Kitchen_Garden"),
+            ]))]);
+            let resp = call_scope(scope(), &torii_url, "/domains?search=GARDEN&page_size=1").await;
+            let resp: serde_json::Value = test::read_body_json(resp).await;
+
+            assert_eq!(resp["data"][0]["id"], "garden_of_live_flowers");
+            assert_eq!(resp["data"].as_array().unwrap().len(), 1);
+            assert_eq!(resp["pagination"]["total"], 2);
+        }
+
+        #[test]
+        fn id_matches_substring_in_any_case() {
+            let id = "garden_of_live_flowers".parse().unwrap();
+
+            assert!(id_matches(&id, "garden"));
+            assert!(id_matches(&id, "Live_Flowers"));
+            assert!(!id_matches(&id, "wonderland"));
+        }
+
+        #[test]
+        fn valid_id_in_path() {
+            let DomainIdInPath(id) = serde_json::from_str("\"wonderland\"").unwrap();
+
+            assert_eq!(id.to_string(), "wonderland");
+        }
+
+        #[test]
+        fn malformed_id_in_path() {
+            let err = serde_json::from_str::<DomainIdInPath>("\"alice@wonderland\"").unwrap_err();
+
+            assert!(err
+                .to_string()
+                .contains("a string in a format `wonderland`"));
+        }
+
+        #[actix_web::test]
+        async fn malformed_id_is_bad_request() {
+            let resp = call_scope(scope(), &unreachable_url(), "/domains/alice@wonderland").await;
+
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        }
+    }
 }
 
 mod assets {
     use super::{
         accounts::AccountIdInPath, asset_definitions::AssetDefinitionIdInPath, etc::StringOf, get,
-        web, AppData, Paginated, PaginationQueryParams, QueryBuilder, Scope, Serialize, WebError,
+        web, AppData, IrohaClientQueryError, Json, Paginated, PaginationQueryParams, QueryBuilder,
+        Scope, Serialize, WebError,
     };
     use iroha_data_model::prelude::{
-        Asset, AssetId, AssetValue, AssetValueType, FindAllAssets, FindAssetById, Identifiable,
-        Metadata,
+        Asset, AssetId, AssetValue, AssetValueType, FindAccountById, FindAllAssets, FindAssetById,
+        FindAssetDefinitionById, Fixed, Identifiable, Metadata,
     };
     use serde::Deserialize;
 
@@ -318,10 +653,32 @@ mod assets {
     pub enum AssetValueDTO {
         Quantity(StringOf<u32>),
         BigQuantity(StringOf<u128>),
+        /// See [`fixed_to_decimal`]
         Fixed(String),
         Store(Metadata),
     }
 
+    /// Formats [`Fixed`] exactly as a plain decimal: an optional `-`, integer digits
+    /// and, if the value has a fractional part, `.` followed by its digits without
+    /// trailing zeros. E.g. `0`, `42`, `-1.5`, `0.000000001`.
+    ///
+    /// There is never an exponent, so the output is parseable by `BigInt`/`BigNumber`-like
+    /// libraries as is.
+    fn fixed_to_decimal(value: Fixed) -> String {
+        normalize_decimal(&value.to_string())
+    }
+
+    /// Strips trailing zeros of the fractional part, along with the `.` if nothing is left of it
+    fn normalize_decimal(decimal: &str) -> String {
+        match decimal.split_once('.') {
+            Some((integer, fraction)) => match fraction.trim_end_matches('0') {
+                "" => integer.to_owned(),
+                fraction => format!("{integer}.{fraction}"),
+            },
+            None => decimal.to_owned(),
+        }
+    }
+
     impl From<AssetValue> for AssetValueDTO {
         fn from(val: AssetValue) -> Self {
             use AssetValue::{BigQuantity, Fixed, Quantity, Store};
@@ -329,7 +686,7 @@ mod assets {
             match val {
                 Quantity(x) => Self::Quantity(x.into()),
                 BigQuantity(x) => Self::BigQuantity(x.into()),
-                Fixed(x) => Self::Fixed(f64::from(x).to_string()),
+                Fixed(x) => Self::Fixed(fixed_to_decimal(x)),
                 Store(x) => Self::Store(x),
             }
         }
@@ -375,45 +732,241 @@ mod assets {
     async fn index(
         data: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
-    ) -> Result<web::Json<Paginated<Vec<AssetDTO>>>, WebError> {
+    ) -> Result<Paginated<Vec<AssetDTO>>, WebError> {
         let data: Paginated<_> = data
             .iroha_client
             .request(
-                QueryBuilder::new(FindAllAssets).with_pagination(pagination.into_inner().into()),
+                QueryBuilder::new(FindAllAssets)
+                    .with_pagination(pagination.into_inner().try_into()?),
             )
             .await
             .map_err(WebError::expect_iroha_any_error)?
             .try_into()?;
-        Ok(web::Json(data.map(|assets| {
-            assets.into_iter().map(Into::into).collect()
-        })))
+        Ok(data.map(|assets| assets.into_iter().map(Into::into).collect()))
     }
 
     #[get("/{definition_id}/{account_id}")]
     async fn show(
         data: web::Data<AppData>,
         path: web::Path<AssetIdInPath>,
-    ) -> Result<web::Json<AssetDTO>, WebError> {
+    ) -> Result<Json<AssetDTO>, WebError> {
         let asset_id: AssetId = path.into_inner().into();
-        let asset = data
+        let asset = match data
             .iroha_client
-            .request(QueryBuilder::new(FindAssetById::new(asset_id)))
+            .request(QueryBuilder::new(FindAssetById::new(asset_id.clone())))
             .await
-            .map_err(WebError::expect_iroha_find_error)?
-            .only_output();
-        Ok(web::Json(asset.into()))
+        {
+            Ok(output) => output.only_output(),
+            Err(IrohaClientQueryError::Validation(_)) => {
+                return Err(explain_missing_asset(&data, asset_id).await)
+            }
+            Err(other) => return Err(WebError::expect_iroha_find_error(other)),
+        };
+        Ok(Json(asset.into()))
+    }
+
+    /// Which part of a missing asset doesn't exist
+    #[derive(Debug)]
+    enum MissingAssetPart {
+        Definition,
+        Account,
+        /// Both the definition and the account exist, but the account has no such asset
+        Asset,
+    }
+
+    impl MissingAssetPart {
+        fn into_error(
+            self,
+            AssetId {
+                definition_id,
+                account_id,
+            }: &AssetId,
+        ) -> WebError {
+            WebError::not_found(match self {
+                Self::Definition => format!("Asset definition `{definition_id}` does not exist"),
+                Self::Account => format!("Account `{account_id}` does not exist"),
+                Self::Asset => format!("Account `{account_id}` has no asset of `{definition_id}`"),
+            })
+        }
+    }
+
+    /// Finds out which part of a missing asset doesn't exist - its definition, its account,
+    /// or only the asset itself - so that the client gets a clear [`WebError::NotFoundWithMessage`]
+    async fn explain_missing_asset(data: &AppData, asset_id: AssetId) -> WebError {
+        match find_missing_asset_part(data, &asset_id).await {
+            Ok(part) => part.into_error(&asset_id),
+            Err(err) => err,
+        }
+    }
+
+    async fn find_missing_asset_part(
+        data: &AppData,
+        AssetId {
+            definition_id,
+            account_id,
+        }: &AssetId,
+    ) -> Result<MissingAssetPart, WebError> {
+        match data
+            .iroha_client
+            .request(QueryBuilder::new(FindAssetDefinitionById::new(
+                definition_id.clone(),
+            )))
+            .await
+        {
+            Ok(_) => {}
+            Err(IrohaClientQueryError::Validation(_)) => return Ok(MissingAssetPart::Definition),
+            Err(other) => return Err(WebError::expect_iroha_find_error(other)),
+        }
+
+        match data
+            .iroha_client
+            .request(QueryBuilder::new(FindAccountById::new(account_id.clone())))
+            .await
+        {
+            Ok(_) => {}
+            Err(IrohaClientQueryError::Validation(_)) => return Ok(MissingAssetPart::Account),
+            Err(other) => return Err(WebError::expect_iroha_find_error(other)),
+        }
+
+        Ok(MissingAssetPart::Asset)
     }
 
     pub fn scope() -> Scope {
         web::scope("/assets").service(index).service(show)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::iroha_client_wrap::test_utils::{
+            account, asset_definition, not_found, scripted_torii_url, StubReply,
+        };
+        use crate::web::call_scope;
+        use actix_web::{http::StatusCode, test};
+
+        /// Requests `rose#wonderland` of `alice@wonderland` from a Torii replying with `replies`
+        async fn missing_asset_body(replies: Vec<StubReply>) -> String {
+            let resp = call_scope(
+                scope(),
+                &scripted_torii_url(replies),
+                "/assets/rose%23wonderland/alice@wonderland",
+            )
+            .await;
+
+            assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+            String::from_utf8(test::read_body(resp).await.to_vec()).unwrap()
+        }
+
+        #[actix_web::test]
+        async fn missing_asset_definition() {
+            // The asset, and then its definition are not found
+            let body = missing_asset_body(vec![Err(not_found()), Err(not_found())]).await;
+
+            assert_eq!(
+                body,
+                "Not Found: Asset definition `rose#wonderland` does not exist"
+            );
+        }
+
+        #[actix_web::test]
+        async fn missing_asset_account() {
+            // The asset is not found, its definition is, and then the account is not
+            let body = missing_asset_body(vec![
+                Err(not_found()),
+                Ok(asset_definition("rose#wonderland")),
+                Err(not_found()),
+            ])
+            .await;
+
+            assert_eq!(body, "Not Found: Account `alice@wonderland` does not exist");
+        }
+
+        #[actix_web::test]
+        async fn missing_asset_of_existing_account() {
+            // Only the asset is not found
+            let body = missing_asset_body(vec![
+                Err(not_found()),
+                Ok(asset_definition("rose#wonderland")),
+                Ok(account("alice@wonderland")),
+            ])
+            .await;
+
+            assert_eq!(
+                body,
+                "Not Found: Account `alice@wonderland` has no asset of `rose#wonderland`"
+            );
+        }
+
+        fn fixed_json(value: f64) -> serde_json::Value {
+            let value = AssetValue::Fixed(Fixed::try_from(value).unwrap());
+            serde_json::to_value(AssetValueDTO::from(value)).unwrap()
+        }
+
+        #[test]
+        fn decimal_integers() {
+            assert_eq!(normalize_decimal("0"), "0");
+            assert_eq!(normalize_decimal("0.0"), "0");
+            assert_eq!(normalize_decimal("42.000000000"), "42");
+            assert_eq!(normalize_decimal("-7.0"), "-7");
+        }
+
+        #[test]
+        fn decimal_fractions_have_no_trailing_zeros() {
+            assert_eq!(normalize_decimal("1.500000000"), "1.5");
+            assert_eq!(normalize_decimal("-0.25"), "-0.25");
+            assert_eq!(normalize_decimal("0.000000001"), "0.000000001");
+            assert_eq!(normalize_decimal("-0.000000001"), "-0.000000001");
+        }
+
+        #[test]
+        fn decimal_of_max_magnitude_is_kept() {
+            assert_eq!(
+                normalize_decimal("9223372036.854775807"),
+                "9223372036.854775807"
+            );
+            assert_eq!(
+                normalize_decimal("-9223372036.854775808"),
+                "-9223372036.854775808"
+            );
+        }
+
+        #[test]
+        fn negative_fixed_is_serialized_as_decimal() {
+            assert_eq!(
+                fixed_json(-1.25),
+                serde_json::json!({ "t": "Fixed", "c": "-1.25" })
+            );
+        }
+
+        #[test]
+        fn fixed_of_max_magnitude_is_serialized_as_decimal() {
+            // The largest integers that fit into `Fixed` with its 9 decimal places
+            assert_eq!(
+                fixed_json(9_223_372_036.0),
+                serde_json::json!({ "t": "Fixed", "c": "9223372036" })
+            );
+            assert_eq!(
+                fixed_json(-9_223_372_036.0),
+                serde_json::json!({ "t": "Fixed", "c": "-9223372036" })
+            );
+        }
+
+        #[test]
+        fn fixed_asset_value_is_serialized_as_decimal() {
+            assert_eq!(
+                fixed_json(1.5),
+                serde_json::json!({ "t": "Fixed", "c": "1.5" })
+            );
+        }
+    }
 }
 
 mod asset_definitions {
     use super::{
-        etc::StringOf, fmt, get, web, AppData, FromStr, Paginated, PaginationQueryParams,
+        etc::StringOf, fmt, get, web, AppData, FromStr, Json, Paginated, PaginationQueryParams,
         QueryBuilder, Scope, Serialize, WebError,
     };
+    use color_eyre::eyre::Context;
     use iroha_data_model::{
         asset::Mintable,
         prelude::{
@@ -421,7 +974,8 @@ mod asset_definitions {
             FindAllAssetsDefinitions, FindAssetDefinitionById, Identifiable,
         },
     };
-    use serde::de;
+    use serde::{de, Deserialize};
+    use std::collections::BTreeSet;
 
     #[derive(Serialize)]
     pub struct AssetDefinitionDTO {
@@ -435,6 +989,34 @@ mod asset_definitions {
         #[serde(flatten)]
         base: AssetDefinitionDTO,
         accounts: Vec<StringOf<AccountId>>,
+        /// Amount of distinct accounts holding the asset. Only with `include_totals=true`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        holders: Option<u32>,
+    }
+
+    impl AssetDefinitionWithAccountsDTO {
+        fn new(
+            base: AssetDefinitionDTO,
+            accounts: Vec<AccountId>,
+            include_totals: bool,
+        ) -> color_eyre::Result<Self> {
+            let holders = if include_totals {
+                let distinct = accounts.iter().collect::<BTreeSet<_>>().len();
+                Some(
+                    distinct
+                        .try_into()
+                        .wrap_err("Failed to convert holders count")?,
+                )
+            } else {
+                None
+            };
+
+            Ok(Self {
+                base,
+                accounts: accounts.into_iter().map(Into::into).collect(),
+                holders,
+            })
+        }
     }
 
     impl AssetDefinitionDTO {
@@ -490,11 +1072,18 @@ mod asset_definitions {
     #[derive(Serialize)]
     pub struct AssetValueTypeDTO(AssetValueType);
 
+    #[derive(Deserialize)]
+    pub struct ShowQueryParams {
+        #[serde(default)]
+        include_totals: bool,
+    }
+
     #[get("/{id}")]
     async fn show(
         app: web::Data<AppData>,
         id: web::Path<AssetDefinitionIdInPath>,
-    ) -> Result<web::Json<AssetDefinitionWithAccountsDTO>, WebError> {
+        web::Query(ShowQueryParams { include_totals }): web::Query<ShowQueryParams>,
+    ) -> Result<Json<AssetDefinitionWithAccountsDTO>, WebError> {
         let definition_id = id.into_inner().0;
 
         let definition = app
@@ -517,31 +1106,31 @@ mod asset_definitions {
             .map_err(WebError::expect_iroha_find_error)?
             .only_output()
             .into_iter()
-            .map(|x| x.id().into())
+            .map(|x| x.id().clone())
             .collect();
 
-        Ok(web::Json(AssetDefinitionWithAccountsDTO {
-            base: definition,
+        Ok(Json(AssetDefinitionWithAccountsDTO::new(
+            definition,
             accounts,
-        }))
+            include_totals,
+        )?))
     }
 
     #[get("")]
     async fn index(
         data: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
-    ) -> Result<web::Json<Paginated<Vec<AssetDefinitionDTO>>>, WebError> {
+    ) -> Result<Paginated<Vec<AssetDefinitionDTO>>, WebError> {
         let data: Paginated<_> = data
             .iroha_client
             .request(
-                QueryBuilder::new(FindAllAssetsDefinitions).with_pagination(pagination.0.into()),
+                QueryBuilder::new(FindAllAssetsDefinitions)
+                    .with_pagination(pagination.0.try_into()?),
             )
             .await
             .map_err(WebError::expect_iroha_any_error)?
             .try_into()?;
-        Ok(web::Json(
-            data.map(|items| items.into_iter().map(Into::into).collect()),
-        ))
+        Ok(data.map(|items| items.into_iter().map(Into::into).collect()))
     }
 
     pub fn scope() -> Scope {
@@ -549,15 +1138,92 @@ mod asset_definitions {
             .service(index)
             .service(show)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::web::path_error_handler;
+        use actix_web::{error::PathError, http::StatusCode, test::TestRequest};
+
+        fn with_accounts(accounts: &[&str], include_totals: bool) -> serde_json::Value {
+            let base = AssetDefinitionDTO {
+                id: "rose#wonderland"
+                    .parse::<AssetDefinitionId>()
+                    .unwrap()
+                    .into(),
+                value_type: AssetValueTypeDTO(AssetValueType::Quantity),
+                mintable: Mintable::Infinitely,
+            };
+            let accounts = accounts.iter().map(|id| id.parse().unwrap()).collect();
+
+            serde_json::to_value(
+                AssetDefinitionWithAccountsDTO::new(base, accounts, include_totals).unwrap(),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn holders_are_counted_with_totals() {
+            let json = with_accounts(&["alice@wonderland", "bob@wonderland"], true);
+
+            assert_eq!(json["holders"], 2);
+        }
+
+        #[test]
+        fn holders_are_counted_once() {
+            let json = with_accounts(&["alice@wonderland", "alice@wonderland"], true);
+
+            assert_eq!(json["holders"], 1);
+        }
+
+        #[test]
+        fn no_holders_without_totals() {
+            let json = with_accounts(&["alice@wonderland"], false);
+
+            assert!(json.get("holders").is_none());
+            assert_eq!(json["accounts"], serde_json::json!(["alice@wonderland"]));
+        }
+
+        #[test]
+        fn valid_id_in_path() {
+            let AssetDefinitionIdInPath(id) = serde_json::from_str("\"rose#wonderland\"").unwrap();
+
+            assert_eq!(id.to_string(), "rose#wonderland");
+        }
+
+        #[test]
+        fn id_in_path_with_wrong_separator() {
+            let err =
+                serde_json::from_str::<AssetDefinitionIdInPath>("\"rose@wonderland\"").unwrap_err();
+
+            assert!(err
+                .to_string()
+                .contains("a string in a format `rose#wonderland`"));
+        }
+
+        #[test]
+        fn malformed_path_is_bad_request() {
+            let err = path_error_handler(
+                PathError::Deserialize(de::Error::custom("malformed id")),
+                &TestRequest::default().to_http_request(),
+            );
+
+            assert_eq!(
+                err.as_response_error().status_code(),
+                StatusCode::BAD_REQUEST
+            );
+        }
+    }
 }
 
 mod peer {
     use super::{
-        etc::StringOf, get, web, AppData, Paginated, PaginationQueryParams, QueryBuilder, Scope,
-        Serialize, WebError,
+        etc::StringOf, get, web, AppData, HttpResponse, Json, Paginated, PaginationQueryParams,
+        QueryBuilder, Scope, Serialize, WebError,
     };
     use iroha_data_model::prelude::{FindAllPeers, Peer, PeerId};
     use iroha_telemetry::metrics::Status;
+    use std::fmt::Write as _;
 
     #[derive(Serialize)]
     pub struct PeerDTO(PeerId);
@@ -615,28 +1281,116 @@ mod peer {
     async fn index_peers(
         data: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
-    ) -> Result<web::Json<Paginated<Vec<PeerDTO>>>, WebError> {
+    ) -> Result<Paginated<Vec<PeerDTO>>, WebError> {
         let data: Paginated<_> = data
             .iroha_client
-            .request(QueryBuilder::new(FindAllPeers).with_pagination(pagination.0.into()))
+            .request(QueryBuilder::new(FindAllPeers).with_pagination(pagination.0.try_into()?))
             .await
             .map_err(WebError::expect_iroha_any_error)?
             .try_into()?;
-        Ok(web::Json(
-            data.map(|items| items.into_iter().map(Into::into).collect()),
-        ))
+        Ok(data.map(|items| items.into_iter().map(Into::into).collect()))
     }
 
     #[get("/status")]
-    async fn index_status(data: web::Data<AppData>) -> Result<web::Json<StatusDTO>, WebError> {
+    async fn index_status(data: web::Data<AppData>) -> Result<Json<StatusDTO>, WebError> {
+        let status = data.iroha_client.get_status().await?;
+        Ok(Json(status.into()))
+    }
+
+    /// Renders [`Status`] in Prometheus text exposition format
+    fn render_metrics(
+        Status {
+            peers,
+            blocks,
+            txs_accepted,
+            txs_rejected,
+            view_changes,
+            ..
+        }: &Status,
+    ) -> String {
+        let metrics = [
+            (
+                "iroha_explorer_peers",
+                "gauge",
+                "Number of peers connected to the Iroha peer",
+                peers,
+            ),
+            (
+                "iroha_explorer_block_height",
+                "gauge",
+                "Number of committed blocks",
+                blocks,
+            ),
+            (
+                "iroha_explorer_transactions_accepted_total",
+                "counter",
+                "Number of accepted transactions",
+                txs_accepted,
+            ),
+            (
+                "iroha_explorer_transactions_rejected_total",
+                "counter",
+                "Number of rejected transactions",
+                txs_rejected,
+            ),
+            (
+                "iroha_explorer_view_changes",
+                "gauge",
+                "Number of view changes in the current round",
+                view_changes,
+            ),
+        ];
+
+        let mut text = String::new();
+        for (name, kind, help, value) in metrics {
+            writeln!(
+                text,
+                "# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}"
+            )
+            .expect("Writing to a string never fails");
+        }
+        text
+    }
+
+    #[get("/metrics")]
+    async fn index_metrics(data: web::Data<AppData>) -> Result<HttpResponse, WebError> {
         let status = data.iroha_client.get_status().await?;
-        Ok(web::Json(status.into()))
+        Ok(HttpResponse::Ok()
+            .content_type("text/plain; version=0.0.4")
+            .body(render_metrics(&status)))
     }
 
     pub fn scope() -> Scope {
         web::scope("/peer")
             .service(index_peers)
             .service(index_status)
+            .service(index_metrics)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn metrics_are_rendered() {
+            let status = Status {
+                peers: 3,
+                blocks: 42,
+                txs_accepted: 100,
+                txs_rejected: 7,
+                view_changes: 1,
+                ..Status::default()
+            };
+
+            let text = render_metrics(&status);
+
+            assert!(text.contains(
+                "# TYPE iroha_explorer_block_height gauge\niroha_explorer_block_height 42\n"
+            ));
+            assert!(text.contains("\niroha_explorer_peers 3\n"));
+            assert!(text.contains("\niroha_explorer_transactions_accepted_total 100\n"));
+            assert!(text.contains("\niroha_explorer_transactions_rejected_total 7\n"));
+        }
     }
 }
 
@@ -660,16 +1414,14 @@ mod roles {
     async fn index(
         app: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
-    ) -> Result<web::Json<Paginated<Vec<RoleDTO>>>, WebError> {
+    ) -> Result<Paginated<Vec<RoleDTO>>, WebError> {
         let data: Paginated<_> = app
             .iroha_client
-            .request(QueryBuilder::new(FindAllRoles).with_pagination(pagination.0.into()))
+            .request(QueryBuilder::new(FindAllRoles).with_pagination(pagination.0.try_into()?))
             .await
             .map_err(WebError::expect_iroha_any_error)?
             .try_into()?;
-        Ok(web::Json(
-            data.map(|items| items.into_iter().map(Into::into).collect()),
-        ))
+        Ok(data.map(|items| items.into_iter().map(Into::into).collect()))
     }
 
     pub fn scope() -> Scope {
@@ -677,47 +1429,240 @@ mod roles {
     }
 }
 
+mod parameters {
+    use super::{
+        etc::StringOf, get, web, AppData, Paginated, PaginationQueryParams, QueryBuilder, Scope,
+        Serialize, WebError,
+    };
+    use iroha_data_model::prelude::{FindAllParameters, Parameter, ParameterId, Value};
+
+    #[derive(Serialize)]
+    pub struct ParameterDTO {
+        id: StringOf<ParameterId>,
+        value: StringOf<Value>,
+    }
+
+    impl From<Parameter> for ParameterDTO {
+        fn from(Parameter { id, val }: Parameter) -> Self {
+            Self {
+                id: id.into(),
+                value: val.into(),
+            }
+        }
+    }
+
+    #[get("")]
+    async fn index(
+        app: web::Data<AppData>,
+        pagination: web::Query<PaginationQueryParams>,
+    ) -> Result<Paginated<Vec<ParameterDTO>>, WebError> {
+        let data: Paginated<_> = app
+            .iroha_client
+            .request(QueryBuilder::new(FindAllParameters).with_pagination(pagination.0.try_into()?))
+            .await
+            .map_err(WebError::expect_iroha_any_error)?
+            .try_into()?;
+        Ok(data.map(|items| items.into_iter().map(Into::into).collect()))
+    }
+
+    pub fn scope() -> Scope {
+        web::scope("/parameters").service(index)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use iroha_data_model::NumericValue;
+
+        #[test]
+        fn parameter_is_serialized_with_string_fields() {
+            let parameter = Parameter {
+                id: ParameterId {
+                    name: "MaxTransactionsInBlock".parse().unwrap(),
+                },
+                val: Value::Numeric(NumericValue::U32(512)),
+            };
+
+            let json = serde_json::to_value(ParameterDTO::from(parameter)).unwrap();
+
+            assert_eq!(
+                json,
+                serde_json::json!({ "id": "MaxTransactionsInBlock", "value": "512" })
+            );
+        }
+    }
+}
+
+mod info {
+    use super::{get, web, Json, Serialize};
+
+    /// Iroha version the explorer is built against. Should match Iroha dependencies
+    /// in `Cargo.toml`.
+    const IROHA_COMPAT: &str = "RC_16";
+
+    /// Identity of the explorer and of the Iroha it is attached to
+    #[derive(Serialize, Clone)]
+    pub struct InfoDTO {
+        explorer_version: String,
+        iroha_compat: &'static str,
+        torii_url: String,
+    }
+
+    impl InfoDTO {
+        pub fn new(explorer_version: String, torii_url: String) -> Self {
+            Self {
+                explorer_version,
+                iroha_compat: IROHA_COMPAT,
+                torii_url,
+            }
+        }
+    }
+
+    #[get("/info")]
+    // actix requires a service to be async
+    #[allow(clippy::unused_async)]
+    pub async fn show(info: web::Data<InfoDTO>) -> Json<InfoDTO> {
+        Json(info.get_ref().clone())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use actix_web::{test, App};
+
+        async fn request_info(info: InfoDTO) -> serde_json::Value {
+            let app =
+                test::init_service(App::new().app_data(web::Data::new(info)).service(show)).await;
+
+            test::call_and_read_body_json(&app, test::TestRequest::get().uri("/info").to_request())
+                .await
+        }
+
+        #[actix_web::test]
+        async fn info_is_reported() {
+            let info = request_info(InfoDTO::new(
+                "0.1.0".to_owned(),
+                "http://127.0.0.1:8080/".to_owned(),
+            ))
+            .await;
+
+            assert_eq!(
+                info,
+                serde_json::json!({
+                    "explorer_version": "0.1.0",
+                    "iroha_compat": IROHA_COMPAT,
+                    "torii_url": "http://127.0.0.1:8080/",
+                })
+            );
+        }
+
+        /// The only test that sets `EXPLORER_VERSION`, so that it doesn't race with others
+        #[actix_web::test]
+        async fn overridden_explorer_version_is_reported() {
+            std::env::set_var("EXPLORER_VERSION", "1.2.3-custom");
+            let explorer_version = crate::args::version();
+            std::env::remove_var("EXPLORER_VERSION");
+
+            let info = request_info(InfoDTO::new(
+                explorer_version,
+                "http://127.0.0.1:8080/".to_owned(),
+            ))
+            .await;
+
+            let keys: Vec<_> = info
+                .as_object()
+                .unwrap()
+                .keys()
+                .map(String::as_str)
+                .collect();
+            assert_eq!(keys, ["explorer_version", "iroha_compat", "torii_url"]);
+            assert_eq!(info["explorer_version"], "1.2.3-custom");
+        }
+    }
+}
+
+pub use info::InfoDTO;
+
+/// Routes `HEAD` requests to the `GET` endpoints. The server itself omits the body of
+/// a response to `HEAD`, keeping the headers, `Content-Length` included.
+fn head_as_get(mut req: ServiceRequest) -> ServiceRequest {
+    if req.method() == http::Method::HEAD {
+        req.head_mut().method = http::Method::GET;
+    }
+    req
+}
+
+/// Responds with `405` if some endpoint matches the path, but not the method. All endpoints
+/// are read-only, so only `GET` and `HEAD` (see [`head_as_get`]) are allowed.
+/// Otherwise, responds with `404`.
 // actix requires a service to be async
 #[allow(clippy::unused_async)]
-async fn default_route() -> impl Responder {
-    HttpResponse::NotFound().body("Not Found")
+async fn default_route(req: HttpRequest) -> impl Responder {
+    if req.resource_map().has_resource(req.path()) {
+        HttpResponse::MethodNotAllowed()
+            .insert_header((http::header::ALLOW, "GET, HEAD"))
+            .body("Method Not Allowed")
+    } else {
+        HttpResponse::NotFound().body("Not Found")
+    }
 }
 
 #[get("")]
 // actix requires a service to be async
 #[allow(clippy::unused_async)]
 async fn root_health_check() -> impl Responder {
+    // A static body is sized, so it is sent with `Content-Length` instead of being chunked,
+    // which some proxies mishandle
     HttpResponse::Ok().body("Welcome to Iroha 2 Block Explorer!")
 }
 
 pub struct ServerInitData {
     iroha_client: Arc<iroha_client::client::Client>,
+    http_config: HttpClientConfig,
+    list_metadata_max_bytes: usize,
+    info: InfoDTO,
 }
 
 impl ServerInitData {
-    pub fn new(iroha_client: Arc<iroha_client::client::Client>) -> Self {
-        Self { iroha_client }
+    pub fn new(
+        iroha_client: Arc<iroha_client::client::Client>,
+        http_config: HttpClientConfig,
+        list_metadata_max_bytes: usize,
+        info: InfoDTO,
+    ) -> Self {
+        Self {
+            iroha_client,
+            http_config,
+            list_metadata_max_bytes,
+            info,
+        }
     }
 }
 
 /// Initializes a server listening on `127.0.0.1:<port>`. It should be awaited to be actually started.
 pub fn server(
-    ServerInitData { iroha_client }: ServerInitData,
+    ServerInitData {
+        iroha_client,
+        http_config,
+        list_metadata_max_bytes,
+        info,
+    }: ServerInitData,
     port: u16,
 ) -> color_eyre::Result<actix_server::Server> {
     let server = HttpServer::new(move || {
-        let client_wrap = crate::iroha_client_wrap::IrohaClientWrap::new(iroha_client.clone());
-        let app_data = web::Data::new(AppData::new(client_wrap));
+        let client_wrap = IrohaClientWrap::new(iroha_client.clone(), &http_config);
+        let app_data = web::Data::new(AppData::new(client_wrap, list_metadata_max_bytes));
 
         App::new()
             .app_data(app_data)
-            .app_data(web::QueryConfig::default().error_handler(|err, _req| {
-                WebError::bad_request(format!("Bad query: {err}")).into()
-            }))
+            .app_data(web::Data::new(info.clone()))
+            .app_data(web::QueryConfig::default().error_handler(query_error_handler))
+            .app_data(web::PathConfig::default().error_handler(path_error_handler))
             // .app_data(web::JsonConfig::default().error_handler(|err, req| {
             //     println!("Json parse error: {err:?}");
             //     WebError::BadRequest("wait".to_owned()).into()
             // }))
+            .wrap_fn(|req, srv| srv.call(head_as_get(req)))
             .wrap(super::logger::TracingLogger::default())
             .wrap(middleware::NormalizePath::new(
                 middleware::TrailingSlash::Trim,
@@ -725,11 +1670,13 @@ pub fn server(
             .service(
                 web::scope("/api/v1")
                     .service(root_health_check)
+                    .service(info::show)
                     .service(accounts::scope())
                     .service(domains::scope())
                     .service(assets::scope())
                     .service(asset_definitions::scope())
                     .service(roles::scope())
+                    .service(parameters::scope())
                     .service(peer::scope())
                     .service(blocks::scope())
                     .service(transactions::scope()),
@@ -741,3 +1688,389 @@ pub fn server(
 
     Ok(server)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iroha_client_wrap::test_utils::{account, rejecting_torii_url, scripted_torii_url};
+    use actix_web::{http::StatusCode, test};
+
+    #[actix_web::test]
+    async fn nonexistent_account_is_not_found() {
+        assert_eq!(
+            call_scope(
+                accounts::scope(),
+                &rejecting_torii_url(),
+                "/accounts/alice@wonderland"
+            )
+            .await
+            .status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[actix_web::test]
+    async fn malformed_account_id_is_bad_request() {
+        assert_eq!(
+            call_scope(accounts::scope(), &rejecting_torii_url(), "/accounts/alice")
+                .await
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn assets_of_nonexistent_account_are_not_found() {
+        assert_eq!(
+            call_scope(
+                accounts::scope(),
+                &rejecting_torii_url(),
+                "/accounts/alice@wonderland/assets"
+            )
+            .await
+            .status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[actix_web::test]
+    async fn assets_of_malformed_account_id_are_bad_request() {
+        assert_eq!(
+            call_scope(
+                accounts::scope(),
+                &rejecting_torii_url(),
+                "/accounts/alice/assets"
+            )
+            .await
+            .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn account_without_assets_has_empty_page() {
+        // The account is found, and then it has no assets
+        let torii_url = scripted_torii_url(vec![
+            Ok(account("alice@wonderland")),
+            Ok(iroha_data_model::prelude::Value::Vec(vec![])),
+        ]);
+        let resp = call_scope(
+            accounts::scope(),
+            &torii_url,
+            "/accounts/alice@wonderland/assets",
+        )
+        .await;
+        let resp: serde_json::Value = test::read_body_json(resp).await;
+
+        assert_eq!(resp["data"], serde_json::json!([]));
+        assert_eq!(resp["pagination"]["total"], 0);
+    }
+
+    #[actix_web::test]
+    async fn nonexistent_asset_is_not_found() {
+        assert_eq!(
+            call_scope(
+                assets::scope(),
+                &rejecting_torii_url(),
+                "/assets/rose%23wonderland/alice@wonderland"
+            )
+            .await
+            .status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[actix_web::test]
+    async fn malformed_asset_id_is_bad_request() {
+        assert_eq!(
+            call_scope(
+                assets::scope(),
+                &rejecting_torii_url(),
+                "/assets/rose@wonderland/alice@wonderland"
+            )
+            .await
+            .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn nonexistent_asset_definition_is_not_found() {
+        assert_eq!(
+            call_scope(
+                asset_definitions::scope(),
+                &rejecting_torii_url(),
+                "/asset-definitions/rose%23wonderland"
+            )
+            .await
+            .status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[actix_web::test]
+    async fn malformed_asset_definition_id_is_bad_request() {
+        assert_eq!(
+            call_scope(
+                asset_definitions::scope(),
+                &rejecting_torii_url(),
+                "/asset-definitions/rose@wonderland"
+            )
+            .await
+            .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn unsupported_method_on_known_path() {
+        let app = test::init_service(
+            App::new()
+                .service(web::scope("/api/v1").service(blocks::scope()))
+                .default_service(web::route().to(default_route)),
+        )
+        .await;
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::post().uri("/api/v1/blocks").to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(
+            resp.headers().get(http::header::ALLOW).unwrap(),
+            "GET, HEAD"
+        );
+    }
+
+    #[actix_web::test]
+    async fn head_is_served_by_get_endpoints() {
+        let app = test::init_service(
+            App::new()
+                .wrap_fn(|req, srv| srv.call(head_as_get(req)))
+                .service(web::scope("/api/v1").service(root_health_check))
+                .default_service(web::route().to(default_route)),
+        )
+        .await;
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::default()
+                .method(http::Method::HEAD)
+                .uri("/api/v1")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn other_methods_are_not_turned_into_get() {
+        let app = test::init_service(
+            App::new()
+                .wrap_fn(|req, srv| srv.call(head_as_get(req)))
+                .service(web::scope("/api/v1").service(root_health_check))
+                .default_service(web::route().to(default_route)),
+        )
+        .await;
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::delete().uri("/api/v1").to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[actix_web::test]
+    async fn bad_query_params() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::QueryConfig::default().error_handler(query_error_handler))
+                .route(
+                    "/paginated",
+                    web::get().to(|_: web::Query<PaginationQueryParams>| async {
+                        HttpResponse::Ok().finish()
+                    }),
+                ),
+        )
+        .await;
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/paginated?page_size=abc")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = test::read_body(resp).await;
+        assert!(std::str::from_utf8(&body)
+            .unwrap()
+            .contains("Bad query: Query deserialize error: `page_size`"));
+    }
+
+    #[actix_web::test]
+    async fn unknown_path() {
+        let app = test::init_service(
+            App::new()
+                .service(web::scope("/api/v1").service(blocks::scope()))
+                .default_service(web::route().to(default_route)),
+        )
+        .await;
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/api/v1/unknown")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn health_check_is_not_chunked() {
+        // Headers are set by the HTTP encoder, so a real server is needed to check them
+        let server = HttpServer::new(|| {
+            App::new().service(web::scope("/api/v1").service(root_health_check))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let addr = server.addrs()[0];
+        actix_web::rt::spawn(server.run());
+
+        let resp = awc::Client::default()
+            .get(format!("http://{addr}/api/v1"))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(resp.headers().contains_key(http::header::CONTENT_LENGTH));
+        assert!(!resp.headers().contains_key(http::header::TRANSFER_ENCODING));
+    }
+
+    #[actix_web::test]
+    async fn not_found_with_message() {
+        let resp = WebError::not_found("Account `alice@wonderland` does not exist".to_owned())
+            .error_response();
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        assert_eq!(body, "Not Found: Account `alice@wonderland` does not exist");
+    }
+
+    #[actix_web::test]
+    async fn malformed_account_id_in_path() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::PathConfig::default().error_handler(path_error_handler))
+                .route(
+                    "/accounts/{id}",
+                    web::get().to(|_: web::Path<accounts::AccountIdInPath>| async {
+                        HttpResponse::Ok().finish()
+                    }),
+                ),
+        )
+        .await;
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/accounts/alice_wonderland")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = test::read_body(resp).await;
+        assert!(std::str::from_utf8(&body)
+            .unwrap()
+            .contains("a string in a format `alice@wonderland`"));
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/accounts/alice@wonderland")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn pretty_json() {
+        let app = test::init_service(App::new().route(
+            "/json",
+            web::get().to(|| async { Json(serde_json::json!({ "a": [1] })) }),
+        ))
+        .await;
+
+        for (uri, expected) in [
+            ("/json", "{\"a\":[1]}"),
+            ("/json?pretty=false", "{\"a\":[1]}"),
+            ("/json?pretty=true", "{\n  \"a\": [\n    1\n  ]\n}"),
+            ("/json?page=2&pretty=true", "{\n  \"a\": [\n    1\n  ]\n}"),
+        ] {
+            let resp =
+                test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert_eq!(
+                resp.headers().get(http::header::CONTENT_TYPE).unwrap(),
+                "application/json"
+            );
+            assert_eq!(test::read_body(resp).await, expected);
+        }
+    }
+
+    #[actix_web::test]
+    async fn etag_and_not_modified() {
+        let app = test::init_service(App::new().route(
+            "/block",
+            web::get().to(|| async {
+                ETagged::new(
+                    Hash::prehashed([1; Hash::LENGTH]),
+                    Json(serde_json::json!({ "a": 1 })),
+                )
+            }),
+        ))
+        .await;
+
+        let resp =
+            test::call_service(&app, test::TestRequest::get().uri("/block").to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let etag = resp.headers().get(http::header::ETAG).unwrap().clone();
+        assert_eq!(etag, format!("W/\"{}\"", "01".repeat(Hash::LENGTH)));
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/block?pretty=true")
+                .insert_header((http::header::IF_NONE_MATCH, etag.clone()))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(resp.headers().get(http::header::ETAG).unwrap(), etag);
+        assert!(test::read_body(resp).await.is_empty());
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/block")
+                .insert_header((http::header::IF_NONE_MATCH, "W/\"other\""))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
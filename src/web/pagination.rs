@@ -1,12 +1,23 @@
 use std::num::NonZeroU32;
 
-use super::{Debug, Serialize};
+use super::{Debug, Json, Serialize, WebError};
+use actix_web::{body::BoxBody, HttpRequest, HttpResponse, Responder};
 use color_eyre::eyre::{eyre, Context, Result};
 use iroha_client::client::ClientQueryRequest;
 use iroha_data_model::prelude::{Pagination as IrohaPagination, Query, QueryBox, Value};
-use serde::Deserialize;
+use serde::{de, Deserialize};
 
-/// Represents some items list with its pagination data
+/// Header with the total count of paginated items, the same as [`PaginationDTO::total`]
+pub const TOTAL_ITEMS_HEADER: &str = "X-Total-Items";
+
+/// Header with the total count of pages, see [`PaginationDTO::total_pages`]
+pub const TOTAL_PAGES_HEADER: &str = "X-Total-Pages";
+
+/// Represents some items list with its pagination data.
+///
+/// Responds with [`Json`] and duplicates the totals into [`TOTAL_ITEMS_HEADER`]
+/// and [`TOTAL_PAGES_HEADER`], so that clients don't need to parse the body to
+/// render page controls.
 #[derive(Serialize, Debug)]
 pub struct Paginated<T> {
     pub pagination: PaginationDTO,
@@ -27,6 +38,51 @@ impl<T> Paginated<T> {
     }
 }
 
+impl<T: Serialize> Responder for Paginated<T> {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse {
+        let total_items = self.pagination.total;
+        let total_pages = self.pagination.total_pages();
+
+        Json(self)
+            .customize()
+            .insert_header((TOTAL_ITEMS_HEADER, total_items))
+            .insert_header((TOTAL_PAGES_HEADER, total_pages))
+            .respond_to(req)
+            .map_into_boxed_body()
+    }
+}
+
+impl<T> Paginated<Vec<T>> {
+    /// Paginates items that are already fetched as a whole, e.g. transactions of a single block.
+    /// Pages beyond the end are empty.
+    ///
+    /// # Errors
+    /// Fails with [`WebError::BadRequest`] if `params` are too large, the same way as
+    /// the conversion into [`IrohaPagination`] does
+    pub fn from_whole(items: Vec<T>, params: PaginationQueryParams) -> Result<Self, WebError> {
+        let PaginationQueryParams { page, page_size } = params;
+        let IrohaPagination { start, limit } = params.try_into()?;
+        let total = items.len().try_into().wrap_err("Failed to convert total")?;
+
+        let data = items
+            .into_iter()
+            .skip(start.unwrap_or(0).try_into().unwrap_or(usize::MAX))
+            .take(limit.map_or(usize::MAX, |limit| limit.try_into().unwrap_or(usize::MAX)))
+            .collect();
+
+        Ok(Self::new(
+            data,
+            PaginationDTO {
+                page,
+                page_size,
+                total,
+            },
+        ))
+    }
+}
+
 impl<R> TryFrom<ClientQueryRequest<R>> for Paginated<R::Output>
 where
     R: Query + Into<QueryBox> + Debug,
@@ -69,6 +125,12 @@ impl PaginationDTO {
             total,
         })
     }
+
+    /// Count of pages with the current page size. It is `0` if there are no items at all.
+    pub fn total_pages(&self) -> u64 {
+        let page_size = u64::from(self.page_size.get());
+        self.total / page_size + u64::from(self.total % page_size != 0)
+    }
 }
 
 /// [`IrohaPagination`] doesn't store a `total` amount of records. This struct does.
@@ -77,6 +139,8 @@ struct IrohaPaginationWithTotal {
     total: u64,
 }
 
+/// `start = 0` is treated the same as an absent `start`.
+///
 /// # Errors
 /// Fails if [`IrohaPagination`] has data that is not aligned to pages.
 /// For example, if there is a `limit = 10`, but `start = 5`, it means that we have a page size = 10,
@@ -91,7 +155,7 @@ impl TryFrom<IrohaPaginationWithTotal> for PaginationDTO {
             total,
         }: IrohaPaginationWithTotal,
     ) -> Result<Self, Self::Error> {
-        match start {
+        match start.filter(|start| *start != 0) {
             None => {
                 let page = 1;
                 let page_size = match limit {
@@ -114,7 +178,7 @@ impl TryFrom<IrohaPaginationWithTotal> for PaginationDTO {
                     Self::from_unchecked_nums(page, page_size, total)
                 }
                 Some(limit) => {
-                    let (page, page_size) = if start % limit == 0 {
+                    let (page, page_size) = if start.checked_rem(limit) == Some(0) {
                         ((start / limit) + 1, limit)
                     } else {
                         return Err(eyre!(
@@ -131,9 +195,12 @@ impl TryFrom<IrohaPaginationWithTotal> for PaginationDTO {
 
 #[derive(Deserialize, Debug)]
 pub struct PaginationQueryParams {
-    #[serde(default = "default_page")]
+    #[serde(default = "default_page", deserialize_with = "deserialize_page")]
     pub page: NonZeroU32,
-    #[serde(default = "default_page_size")]
+    #[serde(
+        default = "default_page_size",
+        deserialize_with = "deserialize_page_size"
+    )]
     pub page_size: NonZeroU32,
 }
 
@@ -155,11 +222,46 @@ const fn default_page_size() -> NonZeroU32 {
     DEFAULT_PAGE_SIZE
 }
 
-impl From<PaginationQueryParams> for IrohaPagination {
-    fn from(PaginationQueryParams { page_size, page }: PaginationQueryParams) -> Self {
-        let page = page.get();
-        let page_size = page_size.get();
-        Self::new(Some((page - 1) * page_size), Some(page_size))
+/// Deserializes a pagination param, naming it in the error message. Otherwise, the message
+/// doesn't tell which param is wrong, e.g. "invalid digit found in string".
+fn deserialize_named_param<'de, D>(name: &str, deserializer: D) -> Result<NonZeroU32, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    NonZeroU32::deserialize(deserializer)
+        .map_err(|err| de::Error::custom(format_args!("`{name}`: {err}")))
+}
+
+fn deserialize_page<'de, D>(deserializer: D) -> Result<NonZeroU32, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    deserialize_named_param("page", deserializer)
+}
+
+fn deserialize_page_size<'de, D>(deserializer: D) -> Result<NonZeroU32, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    deserialize_named_param("page_size", deserializer)
+}
+
+/// # Errors
+/// Fails with [`WebError::BadRequest`] if the page offset doesn't fit into `u32`
+impl TryFrom<PaginationQueryParams> for IrohaPagination {
+    type Error = WebError;
+
+    fn try_from(
+        PaginationQueryParams { page_size, page }: PaginationQueryParams,
+    ) -> Result<Self, Self::Error> {
+        let start = (page.get() - 1)
+            .checked_mul(page_size.get())
+            .ok_or_else(|| {
+                WebError::bad_request(format!(
+                    "`page` ({page}) and `page_size` ({page_size}) are too large"
+                ))
+            })?;
+        Ok(Self::new(Some(start), Some(page_size.get())))
     }
 }
 
@@ -174,12 +276,145 @@ mod tests {
             page_size: 12.try_into().unwrap(),
         };
 
-        let mapped: IrohaPagination = params.into();
+        let mapped: IrohaPagination = params.try_into().unwrap();
 
         assert_eq!(mapped.start, Some(24));
         assert_eq!(mapped.limit, Some(12));
     }
 
+    mod whole_items {
+        use super::*;
+
+        fn paginate(page: u32, page_size: u32) -> Paginated<Vec<u32>> {
+            let params = PaginationQueryParams {
+                page: page.try_into().unwrap(),
+                page_size: page_size.try_into().unwrap(),
+            };
+            Paginated::from_whole((1..=5).collect(), params).unwrap()
+        }
+
+        #[test]
+        fn page_in_the_middle() {
+            let paginated = paginate(2, 2);
+
+            assert_eq!(paginated.data, [3, 4]);
+            assert_eq!(paginated.pagination.page.get(), 2);
+            assert_eq!(paginated.pagination.page_size.get(), 2);
+            assert_eq!(paginated.pagination.total, 5);
+        }
+
+        #[test]
+        fn last_page_is_partial() {
+            assert_eq!(paginate(3, 2).data, [5]);
+        }
+
+        #[test]
+        fn page_beyond_the_end_is_empty() {
+            let paginated = paginate(4, 2);
+
+            assert!(paginated.data.is_empty());
+            assert_eq!(paginated.pagination.total, 5);
+        }
+    }
+
+    mod totals_headers {
+        use super::*;
+        use actix_web::{test, web, App};
+
+        fn pagination(page_size: u32, total: u64) -> PaginationDTO {
+            PaginationDTO::from_unchecked_nums(1, page_size, total).unwrap()
+        }
+
+        #[test]
+        fn total_pages() {
+            assert_eq!(pagination(2, 0).total_pages(), 0);
+            assert_eq!(pagination(2, 1).total_pages(), 1);
+            assert_eq!(pagination(2, 4).total_pages(), 2);
+            assert_eq!(pagination(2, 5).total_pages(), 3);
+        }
+
+        async fn request_headers_and_body(items: Vec<u32>) -> (String, String, serde_json::Value) {
+            let app = test::init_service(App::new().route(
+                "/blocks",
+                web::get().to(move |params: web::Query<PaginationQueryParams>| {
+                    let items = items.clone();
+                    async move { Paginated::from_whole(items, params.into_inner()) }
+                }),
+            ))
+            .await;
+
+            let resp = test::call_service(
+                &app,
+                test::TestRequest::get()
+                    .uri("/blocks?page=2&page_size=2")
+                    .to_request(),
+            )
+            .await;
+            assert!(resp.status().is_success());
+
+            let header = |name| {
+                resp.headers()
+                    .get(name)
+                    .unwrap()
+                    .to_str()
+                    .unwrap()
+                    .to_owned()
+            };
+            let (total_items, total_pages) =
+                (header(TOTAL_ITEMS_HEADER), header(TOTAL_PAGES_HEADER));
+            let body = test::read_body_json(resp).await;
+
+            (total_items, total_pages, body)
+        }
+
+        #[actix_web::test]
+        async fn headers_match_body() {
+            let (total_items, total_pages, body) =
+                request_headers_and_body((1..=5).collect()).await;
+
+            assert_eq!(total_items, "5");
+            assert_eq!(total_pages, "3");
+            assert_eq!(body["pagination"]["total"], 5);
+            assert_eq!(body["data"], serde_json::json!([3, 4]));
+        }
+
+        #[actix_web::test]
+        async fn no_items() {
+            let (total_items, total_pages, body) = request_headers_and_body(Vec::new()).await;
+
+            assert_eq!(total_items, "0");
+            assert_eq!(total_pages, "0");
+            assert_eq!(body["pagination"]["total"], 0);
+        }
+    }
+
+    mod query_params_parsing {
+        use super::*;
+        use actix_web::web::Query;
+
+        #[test]
+        fn defaults() {
+            let Query(params) = Query::<PaginationQueryParams>::from_query("").unwrap();
+
+            assert_eq!(params.page, DEFAULT_PAGE);
+            assert_eq!(params.page_size, DEFAULT_PAGE_SIZE);
+        }
+
+        #[test]
+        fn non_numeric_page_size_is_named() {
+            let err = Query::<PaginationQueryParams>::from_query("page_size=abc").unwrap_err();
+
+            assert!(err.to_string().contains("`page_size`"));
+        }
+
+        #[test]
+        fn zero_page_is_named() {
+            let err = Query::<PaginationQueryParams>::from_query("page=0").unwrap_err();
+
+            assert!(err.to_string().contains("`page`"));
+        }
+    }
+
     mod iroha_pagination_conversion {
         use super::*;
 
@@ -273,4 +508,97 @@ mod tests {
             assert_eq!(result.total, 10);
         }
     }
+
+    /// Exhaustive checks of pagination invariants over ranges of inputs
+    mod properties {
+        use super::*;
+
+        fn params(page: u32, page_size: u32) -> PaginationQueryParams {
+            PaginationQueryParams {
+                page: page.try_into().unwrap(),
+                page_size: page_size.try_into().unwrap(),
+            }
+        }
+
+        #[test]
+        fn query_params_survive_round_trip() {
+            for page in 1..=50 {
+                for page_size in 1..=50 {
+                    for total in [0, 1, u64::from(page * page_size), u64::MAX] {
+                        let pagination = params(page, page_size).try_into().unwrap();
+
+                        let result =
+                            PaginationDTO::try_from(IrohaPaginationWithTotal { pagination, total })
+                                .unwrap();
+
+                        assert_eq!(result.page.get(), page);
+                        assert_eq!(result.page_size.get(), page_size);
+                        assert_eq!(result.total, total);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn pages_cover_every_item_exactly_once() {
+            for total in 0..=100u32 {
+                for page_size in 1..=25 {
+                    let pages = ((total + page_size - 1) / page_size).max(1);
+                    let mut items = Vec::new();
+
+                    // one more page than there are to check an out-of-range page too
+                    for page in 1..=pages + 1 {
+                        let IrohaPagination { start, limit } =
+                            params(page, page_size).try_into().unwrap();
+                        let page_items: Vec<u32> = (start.unwrap()..total)
+                            .take(usize::try_from(limit.unwrap()).unwrap())
+                            .collect();
+
+                        let expected_len = match page.cmp(&pages) {
+                            std::cmp::Ordering::Less => page_size,
+                            std::cmp::Ordering::Equal => total - (pages - 1) * page_size,
+                            std::cmp::Ordering::Greater => 0,
+                        };
+                        assert_eq!(page_items.len(), usize::try_from(expected_len).unwrap());
+
+                        items.extend(page_items);
+                    }
+
+                    assert_eq!(items, (0..total).collect::<Vec<_>>());
+                }
+            }
+        }
+
+        #[test]
+        fn too_large_page_is_rejected() {
+            let err = IrohaPagination::try_from(params(u32::MAX, 2)).unwrap_err();
+
+            assert!(matches!(err, WebError::BadRequest { .. }));
+        }
+
+        #[test]
+        fn zero_start_is_first_page() {
+            for limit in [None, Some(5)] {
+                let pagination = IrohaPaginationWithTotal {
+                    pagination: IrohaPagination::new(Some(0), limit),
+                    total: 5,
+                };
+
+                let result = PaginationDTO::try_from(pagination).unwrap();
+
+                assert_eq!(result.page.get(), 1);
+                assert_eq!(result.page_size.get(), 5);
+            }
+        }
+
+        #[test]
+        fn zero_limit_is_rejected() {
+            let pagination = IrohaPaginationWithTotal {
+                pagination: IrohaPagination::new(Some(5), Some(0)),
+                total: 5,
+            };
+
+            let _err = PaginationDTO::try_from(pagination).unwrap_err();
+        }
+    }
 }
@@ -3,8 +3,9 @@ use std::collections::BTreeSet;
 use crate::{iroha_client_wrap::QueryBuilder, web::etc::HashDeser};
 
 use super::{
+    blocks::latest_blocks,
     etc::{SerScaleHex, Timestamp},
-    get, web, AppData, Paginated, PaginationQueryParams, Scope, WebError,
+    get, web, AppData, ETagged, Json, Paginated, PaginationQueryParams, Scope, WebError,
 };
 use crate::web::etc::SignatureDTO;
 use color_eyre::{eyre::Context, Result};
@@ -16,11 +17,20 @@ use iroha_data_model::prelude::{
     UnlimitedMetadata,
 };
 use iroha_data_model::transaction::{
-    error::model::TransactionRejectionReason, model::TransactionPayload,
+    error::model::TransactionRejectionReason, model::TransactionPayload, WasmSmartContract,
 };
 
 use core::num::{NonZeroU32, NonZeroU64};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Max amount of the latest blocks that `/transactions/rejections` may look through
+const REJECTIONS_MAX_BLOCKS: u32 = 1000;
+
+const DEFAULT_REJECTIONS_BLOCKS: NonZeroU32 = match NonZeroU32::new(100) {
+    Some(v) => v,
+    None => panic!("Failed to make default amount of blocks with rejections"),
+};
 
 #[derive(Serialize)]
 pub struct TransactionDTO {
@@ -51,6 +61,21 @@ impl TryFrom<TransactionQueryResult> for TransactionDTO {
 }
 
 impl TransactionDTO {
+    /// Constructs from a transaction contained in the block with `block_hash`
+    pub fn from_block_value(
+        TransactionValue { tx, error }: TransactionValue,
+        block_hash: HashOf<CommittedBlock>,
+    ) -> Result<Self> {
+        Self::new(
+            tx.hash(),
+            block_hash,
+            tx.payload().clone(),
+            tx.signatures().clone(),
+            error,
+        )
+        .wrap_err("Failed to make TransactionDTO")
+    }
+
     fn new(
         hash: HashOf<VersionedSignedTransaction>,
         block_hash: HashOf<CommittedBlock>,
@@ -76,6 +101,8 @@ impl TransactionDTO {
 pub struct TransactionPayloadDTO {
     account_id: String,
     instructions: ExecutableDTO,
+    /// Present only if the transaction is a WASM smart contract
+    wasm: Option<WasmInfoDTO>,
     creation_time: Timestamp,
     time_to_live_ms: Option<NonZeroU64>,
     nonce: Option<NonZeroU32>,
@@ -88,6 +115,7 @@ impl TryFrom<TransactionPayload> for TransactionPayloadDTO {
     fn try_from(payload: TransactionPayload) -> Result<Self, Self::Error> {
         Ok(Self {
             account_id: payload.authority.to_string(),
+            wasm: wasm_info(&payload.instructions),
             instructions: payload.instructions.into(),
             creation_time: Timestamp::try_from(payload.creation_time_ms)
                 .wrap_err("Failed to map creation_time")?,
@@ -118,11 +146,39 @@ impl From<Executable> for ExecutableDTO {
     }
 }
 
+/// Size and hash of a WASM smart contract, so that it could be identified
+/// without transferring the module itself
+#[derive(Serialize)]
+pub struct WasmInfoDTO {
+    /// Size of the module in bytes
+    size: usize,
+    /// SHA-256 hash of the module, hex
+    sha256: String,
+}
+
+impl From<&WasmSmartContract> for WasmInfoDTO {
+    fn from(wasm: &WasmSmartContract) -> Self {
+        let blob: &[u8] = wasm.as_ref();
+        Self {
+            size: blob.len(),
+            sha256: hex::encode(Sha256::digest(blob)),
+        }
+    }
+}
+
+fn wasm_info(executable: &Executable) -> Option<WasmInfoDTO> {
+    match executable {
+        Executable::Instructions(_) => None,
+        Executable::Wasm(wasm) => Some(wasm.into()),
+    }
+}
+
+/// Committed transactions never change, so the response is tagged with the transaction hash
 #[get("/{hash}")]
 async fn show(
     app: web::Data<AppData>,
     hash: web::Path<HashDeser>,
-) -> Result<web::Json<TransactionDTO>, WebError> {
+) -> Result<ETagged<Json<TransactionDTO>>, WebError> {
     let hash = hash.into_inner().0;
     let tx = app
         .iroha_client
@@ -134,19 +190,134 @@ async fn show(
         .map_err(WebError::expect_iroha_find_error)?
         .only_output();
 
-    Ok(web::Json(
-        tx.try_into().wrap_err("Failed to map TransactionValue")?,
+    Ok(ETagged::new(
+        hash,
+        Json(tx.try_into().wrap_err("Failed to map TransactionValue")?),
     ))
 }
 
+/// A single instruction of a transaction, addressed by the transaction hash
+/// and the instruction index within it
+#[derive(Serialize)]
+pub struct InstructionDTO {
+    transaction_hash: SerScaleHex<HashOf<VersionedSignedTransaction>>,
+    index: usize,
+    instruction: SerScaleHex<InstructionBox>,
+}
+
+#[derive(Deserialize)]
+pub struct InstructionInPath {
+    hash: HashDeser,
+    index: usize,
+}
+
+/// Picks the instruction at `index` of a transaction. Responds with
+/// [`WebError::NotFoundWithMessage`] if the index is out of range or the transaction
+/// is a WASM smart contract.
+fn select_instruction(executable: &Executable, index: usize) -> Result<InstructionBox, WebError> {
+    match executable {
+        Executable::Instructions(items) => items.get(index).cloned().ok_or_else(|| {
+            WebError::not_found(format!(
+                "Transaction has {} instructions, there is no instruction #{index}",
+                items.len()
+            ))
+        }),
+        Executable::Wasm(_) => Err(WebError::not_found(
+            "Transaction is a WASM smart contract, its instructions can't be addressed".to_owned(),
+        )),
+    }
+}
+
+#[get("/{hash}/instructions/{index}")]
+async fn show_instruction(
+    app: web::Data<AppData>,
+    path: web::Path<InstructionInPath>,
+) -> Result<Json<InstructionDTO>, WebError> {
+    let InstructionInPath {
+        hash: HashDeser(hash),
+        index,
+    } = path.into_inner();
+    let tx_result = app
+        .iroha_client
+        .request(QueryBuilder::new(FindTransactionByHash::new(
+            #[allow(deprecated)]
+            HashOf::from_untyped_unchecked(hash),
+        )))
+        .await
+        .map_err(WebError::expect_iroha_find_error)?
+        .only_output();
+
+    let TransactionValue { tx, .. } = tx_result.transaction();
+    let instruction = select_instruction(&tx.payload().instructions, index)?;
+
+    Ok(Json(InstructionDTO {
+        transaction_hash: tx.hash().into(),
+        index,
+        instruction: instruction.into(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct RejectionsQueryParams {
+    #[serde(default = "default_rejections_blocks")]
+    blocks: NonZeroU32,
+}
+
+const fn default_rejections_blocks() -> NonZeroU32 {
+    DEFAULT_REJECTIONS_BLOCKS
+}
+
+/// Rejected transactions of the latest `blocks` blocks, the latest first, along with
+/// their rejection reasons. Iroha can't filter transactions by rejection, so the blocks
+/// are fetched as a whole and the rejections are paginated by the explorer itself.
+#[get("/rejections")]
+async fn rejections(
+    app: web::Data<AppData>,
+    web::Query(RejectionsQueryParams { blocks }): web::Query<RejectionsQueryParams>,
+    pagination: web::Query<PaginationQueryParams>,
+) -> Result<Paginated<Vec<TransactionDTO>>, WebError> {
+    let blocks = blocks.get();
+    if blocks > REJECTIONS_MAX_BLOCKS {
+        return Err(WebError::bad_request(format!(
+            "`blocks` ({blocks}) must not exceed {REJECTIONS_MAX_BLOCKS}"
+        )));
+    }
+
+    let rejected: Vec<_> = latest_blocks(&app, blocks)
+        .await?
+        .into_iter()
+        .rev()
+        .flat_map(|block| {
+            let block = block.into_v1();
+            let block_hash = block.hash();
+            block
+                .transactions
+                .into_iter()
+                .rev()
+                .filter(|tx| tx.error.is_some())
+                .map(move |tx| (tx, block_hash))
+        })
+        .collect();
+
+    let paginated = Paginated::from_whole(rejected, pagination.into_inner())?;
+    let data = paginated
+        .data
+        .into_iter()
+        .map(|(tx, block_hash)| TransactionDTO::from_block_value(tx, block_hash))
+        .collect::<Result<Vec<_>>>()
+        .wrap_err("Failed to construct TransactionDTO")?;
+
+    Ok(Paginated::new(data, paginated.pagination))
+}
+
 #[get("")]
 async fn index(
     app: web::Data<AppData>,
     pagination: web::Query<PaginationQueryParams>,
-) -> Result<web::Json<Paginated<Vec<TransactionDTO>>>, WebError> {
+) -> Result<Paginated<Vec<TransactionDTO>>, WebError> {
     let Paginated { data, pagination } = app
         .iroha_client
-        .request(QueryBuilder::new(FindAllTransactions).with_pagination(pagination.0.into()))
+        .request(QueryBuilder::new(FindAllTransactions).with_pagination(pagination.0.try_into()?))
         .await
         .map_err(WebError::expect_iroha_any_error)?
         .try_into()?;
@@ -157,9 +328,146 @@ async fn index(
         .collect::<Result<Vec<_>>>()
         .wrap_err("Failed to construct TransactionDTO")?;
 
-    Ok(web::Json(Paginated::new(data, pagination)))
+    Ok(Paginated::new(data, pagination))
 }
 
 pub fn scope() -> Scope {
-    web::scope("/transactions").service(index).service(show)
+    web::scope("/transactions")
+        .service(index)
+        // before `show`, so that `rejections` is not treated as a transaction hash
+        .service(rejections)
+        .service(show)
+        .service(show_instruction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{iroha_client_wrap::test_utils::unreachable_url, web::call_scope};
+    use actix_web::http::StatusCode;
+    use iroha_data_model::prelude::FailBox;
+
+    const HASH: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+
+    #[actix_web::test]
+    async fn too_many_blocks_with_rejections() {
+        assert_eq!(
+            call_scope(
+                scope(),
+                &unreachable_url(),
+                "/transactions/rejections?blocks=1001"
+            )
+            .await
+            .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn zero_blocks_with_rejections() {
+        assert_eq!(
+            call_scope(
+                scope(),
+                &unreachable_url(),
+                "/transactions/rejections?blocks=0"
+            )
+            .await
+            .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn malformed_hash_is_bad_request() {
+        assert_eq!(
+            call_scope(scope(), &unreachable_url(), "/transactions/not-a-hash")
+                .await
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn instruction_index_beyond_usize_is_bad_request() {
+        assert_eq!(
+            call_scope(
+                scope(),
+                &unreachable_url(),
+                &format!("/transactions/{HASH}/instructions/18446744073709551616")
+            )
+            .await
+            .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn page_beyond_u32_is_bad_request() {
+        assert_eq!(
+            call_scope(scope(), &unreachable_url(), "/transactions?page=4294967296")
+                .await
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[test]
+    fn wasm_size_and_hash_are_reported() {
+        let executable = Executable::Wasm(WasmSmartContract::from_compiled(b"abc".to_vec()));
+
+        let json = serde_json::to_value(wasm_info(&executable)).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "size": 3,
+                "sha256": "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+            })
+        );
+    }
+
+    #[test]
+    fn no_wasm_info_for_instructions() {
+        let executable = Executable::Instructions(vec![FailBox::new("fail").into()]);
+
+        assert!(wasm_info(&executable).is_none());
+    }
+
+    fn instructions(reasons: &[&str]) -> Executable {
+        Executable::Instructions(
+            reasons
+                .iter()
+                .map(|reason| FailBox::new(*reason).into())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn instruction_in_range_is_selected() {
+        let instruction = select_instruction(&instructions(&["first", "second"]), 1).unwrap();
+
+        assert_eq!(instruction, FailBox::new("second").into());
+    }
+
+    #[test]
+    fn instruction_out_of_range_is_not_found() {
+        let err = select_instruction(&instructions(&["first", "second"]), 2).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Not Found: Transaction has 2 instructions, there is no instruction #2"
+        );
+    }
+
+    #[test]
+    fn instruction_of_wasm_is_not_found() {
+        let executable = Executable::Wasm(WasmSmartContract::from_compiled(b"abc".to_vec()));
+
+        let err = select_instruction(&executable, 0).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Not Found: Transaction is a WASM smart contract, its instructions can't be addressed"
+        );
+    }
 }
@@ -15,14 +15,15 @@ mod model {
         genesis::{GenesisTransaction, RawGenesisBlock},
         tx::MintBox,
     };
-    use iroha_crypto::{KeyGenConfiguration, KeyPair};
+    use iroha_crypto::{KeyGenConfiguration, KeyPair, PrivateKey, PublicKey};
     use iroha_data_model::{
         account::NewAccount,
         asset::NewAssetDefinition,
+        metadata::Limits as MetadataLimits,
         prelude::{
             Account as OriginAccount, AccountId, AssetDefinition as OriginAssetDefinition,
             AssetDefinitionId, AssetId, AssetValueType, Domain as OriginDomain, DomainId,
-            Instruction, Name, RegisterBox, Value,
+            Instruction, Metadata, Name, RegisterBox, Value,
         },
     };
     use itertools::Itertools;
@@ -38,6 +39,12 @@ mod model {
     /// [issue](https://github.com/hyperledger/iroha/issues/2232)
     const TRANSACTION_ISI_CHUNK_SIZE: usize = 1400;
 
+    /// Limits of the generated metadata. The same as Iroha's default limits
+    /// for domains, accounts and asset definitions.
+    fn metadata_limits() -> MetadataLimits {
+        MetadataLimits::new(1024, 4096)
+    }
+
     pub struct View {
         chunks: Vec<SingleChunkView>,
     }
@@ -97,17 +104,20 @@ mod model {
                 Vec::with_capacity(config.asset_actions.try_into()?);
 
             for _ in 0..config.domains {
-                let domain = Domain::new(rand_help).wrap_err("Failed to generate domain")?;
+                let mut domain = Domain::new(rand_help).wrap_err("Failed to generate domain")?;
+                domain.metadata = rand_help.gen_metadata(config)?;
 
                 for _ in 0..config.accounts_per_domain {
-                    let account = Account::new(rand_help, &domain.id)
+                    let mut account = Account::new(rand_help, &domain.id)
                         .wrap_err("Failed to generate account")?;
+                    account.metadata = rand_help.gen_metadata(config)?;
                     accounts.push(account);
                 }
 
                 for _ in 0..config.assets_per_domain {
-                    let definition = AssetDefinition::new(rand_help, &domain.id)
+                    let mut definition = AssetDefinition::new(rand_help, &domain.id)
                         .wrap_err("Failed to generage asset definition")?;
+                    definition.metadata = rand_help.gen_metadata(config)?;
                     asset_definitions.push(definition);
                 }
 
@@ -135,7 +145,7 @@ mod model {
         fn into_isis(self) -> impl Iterator<Item = Instruction> {
             self.domains
                 .into_iter()
-                .map(|x| RegisterBox::new(OriginDomain::new(x.id)).into())
+                .map(|x| RegisterBox::new(OriginDomain::new(x.id).with_metadata(x.metadata)).into())
                 .chain(
                     self.accounts
                         .clone()
@@ -161,6 +171,27 @@ mod model {
         pub fn only_genesis(self) -> RawGenesisBlock {
             self.raw_genesis_block
         }
+
+        /// Key pairs of all generated accounts
+        pub fn account_keys(&self) -> Vec<AccountKeys> {
+            self.accounts
+                .iter()
+                .flat_map(|Account { id, keys, .. }| {
+                    keys.iter().map(|key_pair| AccountKeys {
+                        account_id: id.to_string(),
+                        public_key: key_pair.public_key().clone(),
+                        private_key: key_pair.private_key().clone(),
+                    })
+                })
+                .collect()
+        }
+    }
+
+    #[derive(Serialize)]
+    pub struct AccountKeys {
+        account_id: String,
+        public_key: PublicKey,
+        private_key: PrivateKey,
     }
 
     /// For used names tracking
@@ -195,10 +226,57 @@ mod model {
                 used_names,
             }
         }
+
+        /// Generates metadata with `metadata_keys_per_entity` random keys. Values are
+        /// strings, numbers, booleans, arrays and maps, nested up to `metadata_max_depth`.
+        fn gen_metadata(&mut self, config: &CLI) -> Result<Metadata> {
+            self.gen_metadata_map(config.metadata_keys_per_entity, config.metadata_max_depth)
+                .wrap_err("Failed to generate metadata")
+        }
+
+        fn gen_metadata_map(&mut self, keys: u32, depth: u32) -> Result<Metadata> {
+            let mut metadata = Metadata::new();
+            // Repeated keys are just overwritten, so there may be less keys than requested
+            for _ in 0..keys {
+                let key = construct_iroha_name(&self.petnames.generate(&mut self.rng, 1, ""))?;
+                let value = self.gen_metadata_value(depth)?;
+                metadata
+                    .insert_with_limits(key, value, metadata_limits())
+                    .map_err(|err| eyre!("Failed to insert metadata value: {err}"))?;
+            }
+            Ok(metadata)
+        }
+
+        fn gen_metadata_value(&mut self, depth: u32) -> Result<Value> {
+            // Only scalars at the deepest level
+            let kinds = if depth == 0 { 0..3 } else { 0..5 };
+
+            let value = match self.rng.gen_range(kinds) {
+                0 => Value::String(self.petnames.generate(&mut self.rng, 2, " ")),
+                1 => Value::from(self.rng.gen_range(0..1_000_000u32)),
+                2 => Value::Bool(self.rng.gen()),
+                3 => {
+                    let len = self.rng.gen_range(1..=3);
+                    Value::Vec(
+                        (0..len)
+                            .map(|_| self.gen_metadata_value(depth - 1))
+                            .collect::<Result<_>>()?,
+                    )
+                }
+                4 => {
+                    let keys = self.rng.gen_range(1..=3);
+                    Value::LimitedMetadata(self.gen_metadata_map(keys, depth - 1)?)
+                }
+                x => return Err(eyre!("Unexpected random num: {x}")),
+            };
+
+            Ok(value)
+        }
     }
 
     struct Domain {
         id: DomainId,
+        metadata: Metadata,
     }
 
     impl Domain {
@@ -213,13 +291,16 @@ mod model {
 
             Ok(Domain {
                 id: DomainId::new(name),
+                metadata: Metadata::new(),
             })
         }
     }
 
     impl From<Domain> for OriginDomain {
         fn from(domain: Domain) -> Self {
-            OriginDomain::new(domain.id).build()
+            OriginDomain::new(domain.id)
+                .with_metadata(domain.metadata)
+                .build()
         }
     }
 
@@ -227,11 +308,15 @@ mod model {
     struct Account {
         id: AccountId,
         keys: Vec<KeyPair>,
+        /// Already contained in the genesis
+        #[serde(skip)]
+        metadata: Metadata,
     }
 
     impl From<Account> for NewAccount {
-        fn from(Account { id, keys }: Account) -> Self {
+        fn from(Account { id, keys, metadata }: Account) -> Self {
             OriginAccount::new(id, keys.iter().map(|x| x.public_key().clone()))
+                .with_metadata(metadata)
         }
     }
 
@@ -251,7 +336,11 @@ mod model {
 
             let id = Self::gen_account_id(rnd, domain_id)?;
 
-            Ok(Self { id, keys })
+            Ok(Self {
+                id,
+                keys,
+                metadata: Metadata::new(),
+            })
         }
 
         fn gen_account_id(rnd: &mut RandHelp, domain_id: &DomainId) -> Result<AccountId> {
@@ -270,6 +359,7 @@ mod model {
         id: AssetDefinitionId,
         value_type: AssetValueType,
         mintable: InitialMintable,
+        metadata: Metadata,
     }
 
     /// As [`Mintable`], but does not allow [`Mintable::Not`], because it is not
@@ -286,6 +376,7 @@ mod model {
                 id,
                 value_type,
                 mintable,
+                metadata,
             }: AssetDefinition,
         ) -> Self {
             use AssetValueType::{BigQuantity, Fixed, Quantity, Store};
@@ -301,7 +392,7 @@ mod model {
                 definition = definition.mintable_once();
             }
 
-            definition
+            definition.with_metadata(metadata)
         }
     }
 
@@ -335,6 +426,7 @@ mod model {
                 id,
                 value_type,
                 mintable,
+                metadata: Metadata::new(),
             })
         }
     }
@@ -451,16 +543,19 @@ mod model {
                     id: "rose#wonderland".parse().unwrap(),
                     value_type: AssetValueType::Quantity,
                     mintable: InitialMintable::Infinitely,
+                    metadata: Metadata::new(),
                 },
                 AssetDefinition {
                     id: "tulip#wonderland".parse().unwrap(),
                     value_type: AssetValueType::Quantity,
                     mintable: InitialMintable::Once,
+                    metadata: Metadata::new(),
                 },
             ];
             let accounts = vec![Account {
                 id: "alice@wonderland".parse().unwrap(),
                 keys: vec![],
+                metadata: Metadata::new(),
             }];
             let mut gen = AssetActionGen::new(&assets, &accounts);
 
@@ -495,7 +590,10 @@ mod model {
                 asset_actions: 5_000,
                 minify: false,
                 only_genesis: false,
+                keys_out: None,
                 chunks: 5.try_into().unwrap(),
+                metadata_keys_per_entity: 0,
+                metadata_max_depth: 0,
             })
             .unwrap()
             .build();
@@ -505,6 +603,60 @@ mod model {
             }
         }
 
+        fn generate_with_metadata(metadata_keys_per_entity: u32) -> View {
+            View::generate(&CLI {
+                domains: 2,
+                accounts_per_domain: 2,
+                assets_per_domain: 2,
+                asset_actions: 0,
+                minify: false,
+                only_genesis: false,
+                keys_out: None,
+                chunks: 1.try_into().unwrap(),
+                metadata_keys_per_entity,
+                metadata_max_depth: 3,
+            })
+            .unwrap()
+        }
+
+        #[test]
+        fn entities_have_metadata_if_requested() {
+            let view = generate_with_metadata(3);
+
+            for chunk in view.chunks {
+                for Domain { metadata, .. } in chunk.domains {
+                    assert_ne!(metadata, Metadata::new());
+                }
+                for Account { metadata, .. } in chunk.accounts {
+                    assert_ne!(metadata, Metadata::new());
+                }
+                for AssetDefinition { metadata, .. } in chunk.asset_definitions {
+                    assert_ne!(metadata, Metadata::new());
+                }
+            }
+        }
+
+        #[test]
+        fn no_metadata_by_default() {
+            let view = generate_with_metadata(0);
+
+            for chunk in view.chunks {
+                for Domain { metadata, .. } in chunk.domains {
+                    assert_eq!(metadata, Metadata::new());
+                }
+            }
+        }
+
+        #[test]
+        fn genesis_with_metadata_is_parsed_back() {
+            let ViewBuilt {
+                raw_genesis_block, ..
+            } = generate_with_metadata(3).build();
+            let json = serde_json::to_string(&raw_genesis_block).unwrap();
+
+            let _parsed: RawGenesisBlock = serde_json::from_str(&json).unwrap();
+        }
+
         #[test]
         fn domains_are_not_repeated() {
             repetition_test_factory(
@@ -568,6 +720,12 @@ pub fn generate(args: &CLI) -> Result<String> {
         .wrap_err("Failed to generate model")?
         .build();
 
+    if let Some(path) = &args.keys_out {
+        let keys = to_json(&model.account_keys(), args.minify)?;
+        std::fs::write(path, keys)
+            .wrap_err_with(|| format!("Failed to write keys into {}", path.display()))?;
+    }
+
     if args.only_genesis {
         to_json(&model.only_genesis(), args.minify)
     } else {
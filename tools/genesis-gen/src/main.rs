@@ -1,4 +1,4 @@
-use std::num::NonZeroU32;
+use std::{num::NonZeroU32, path::PathBuf};
 
 use clap::Parser;
 use color_eyre::Result;
@@ -12,10 +12,7 @@ mod gen;
 ///
 /// - Domains, Accounts and Asset Definitions
 /// - Asset lifecycle actions (mints, burns, transfers)
-///
-/// TODO:
-///
-/// - Generate random (or not so) metadata
+/// - Random metadata of Domains, Accounts and Asset Definitions
 #[derive(Parser, Debug)]
 pub struct CLI {
     /// Minify output JSON
@@ -24,6 +21,10 @@ pub struct CLI {
     /// Print only genesis, without generated accounts data
     #[clap(long, short = 'g')]
     only_genesis: bool,
+    /// Write key pairs of the generated accounts into this file, as a JSON array of
+    /// `{ account_id, public_key, private_key }`
+    #[clap(long)]
+    keys_out: Option<PathBuf>,
     /// How many domains to generate
     #[clap(long, short, default_value = "5")]
     pub domains: u32,
@@ -42,6 +43,12 @@ pub struct CLI {
     /// data chunks to generate
     #[clap(long, default_value = "1")]
     pub chunks: NonZeroU32,
+    /// How many metadata keys to generate for each domain, account and asset definition
+    #[clap(long, default_value = "0")]
+    pub metadata_keys_per_entity: u32,
+    /// How deep metadata values (arrays and maps) may be nested
+    #[clap(long, default_value = "2")]
+    pub metadata_max_depth: u32,
 }
 
 impl CLI {
@@ -72,3 +79,60 @@ fn main() -> Result<()> {
     println!("{generated}");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_names_account_for_all_chunks() {
+        let cli = CLI::try_parse_from([
+            "genesis-gen",
+            "--domains",
+            "5",
+            "--accounts-per-domain",
+            "7",
+            "--assets-per-domain",
+            "2",
+            "--chunks",
+            "3",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.total_domain_names(), 5 * 3);
+        assert_eq!(cli.total_account_names(), 5 * 7 * 3);
+        assert_eq!(cli.total_asset_names(), 5 * 2 * 3);
+    }
+
+    #[test]
+    fn keys_are_written_into_file() {
+        let keys_out = std::env::temp_dir().join(format!(
+            "genesis_gen_keys_out_test_{}.json",
+            std::process::id()
+        ));
+        let cli = CLI::try_parse_from([
+            "genesis-gen",
+            "--domains",
+            "2",
+            "--accounts-per-domain",
+            "3",
+            "--chunks",
+            "2",
+            "--keys-out",
+            keys_out.to_str().unwrap(),
+        ])
+        .unwrap();
+
+        gen::generate(&cli).unwrap();
+        let written = std::fs::read_to_string(&keys_out).unwrap();
+        std::fs::remove_file(&keys_out).unwrap();
+
+        let keys: Vec<serde_json::Value> = serde_json::from_str(&written).unwrap();
+        assert_eq!(keys.len(), 2 * 3 * 2);
+        for key in keys {
+            assert!(key["account_id"].is_string());
+            assert!(!key["public_key"].is_null());
+            assert!(!key["private_key"].is_null());
+        }
+    }
+}